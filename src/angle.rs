@@ -0,0 +1,309 @@
+use core::f32::consts::TAU;
+
+/// An angle which can wrap around a circle, such as [Radians] or [Degrees].
+///
+/// A [TweenValue](crate::TweenValue) is tweened by adding part of the difference between its start
+/// and end to the start, so a plain `f32` tweened from `350.0` to `10.0` spins 340 degrees the
+/// long way around. An `Angle` can instead pick the end it should really tween to with
+/// [path_to](Self::path_to), which is what [Tweener::angle](crate::Tweener::angle) does:
+///
+/// ```
+/// # use tween::{AnglePath, Degrees, Linear, Tweener};
+/// let mut tweener = Tweener::angle(
+///     Degrees(350.0),
+///     Degrees(10.0),
+///     10,
+///     Linear,
+///     AnglePath::Shortest,
+/// );
+///
+/// assert_eq!(tweener.move_to(5), Degrees(360.0));
+/// assert_eq!(tweener.move_to(10), Degrees(370.0));
+/// assert_eq!(tweener.move_to(10).wrapped(), Degrees(10.0));
+/// ```
+pub trait Angle: crate::TweenValue {
+    /// Returns the equivalent angle within one turn, from zero up to (but not including) a full
+    /// turn.
+    fn wrapped(self) -> Self;
+
+    /// Returns the angle equivalent to `end` that tweening from `self` should finish at to go
+    /// around the circle by `path`.
+    ///
+    /// The returned angle isn't wrapped, so tweens carry on smoothly from wherever `self` is, even
+    /// if it has spun around many times. Use [wrapped](Self::wrapped) to bring it back within one
+    /// turn.
+    fn path_to(self, end: Self, path: AnglePath) -> Self;
+}
+
+/// Which way around the circle an [Angle] tweens.
+///
+/// Angles increase counter-clockwise, as is standard in math and in libraries like `glam` and
+/// `nalgebra`. If your y axis points down, as it often does in screen space, increasing angles
+/// will instead look clockwise on screen.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub enum AnglePath {
+    /// Take the shorter way around the circle. When both ways are half a turn, the angle keeps
+    /// the direction of the raw difference. This is the default.
+    #[default]
+    Shortest,
+
+    /// Always turn clockwise, which is the direction of decreasing angle.
+    Clockwise,
+
+    /// Always turn counter-clockwise, which is the direction of increasing angle.
+    CounterClockwise,
+
+    /// Tween directly from the start to the end, without wrapping, just like a plain `f32`. This
+    /// is useful for deliberately spinning several times.
+    Raw,
+}
+
+/// An angle in radians, where a full turn is `TAU`.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct Radians(pub f32);
+
+/// An angle in degrees, where a full turn is `360.0`.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct Degrees(pub f32);
+
+macro_rules! impl_angle {
+    ($name:ident, $full_turn:expr) => {
+        impl $name {
+            /// A full turn around the circle.
+            pub const FULL_TURN: Self = Self($full_turn);
+
+            /// Returns the equivalent angle within one turn. See [Angle::wrapped].
+            #[inline]
+            pub fn wrapped(self) -> Self {
+                let output = self.0 % $full_turn;
+                Self(if output < 0.0 { output + $full_turn } else { output })
+            }
+
+            /// Returns the angle `end` should be to go around the circle by `path`. See
+            /// [Angle::path_to].
+            pub fn path_to(self, end: Self, path: AnglePath) -> Self {
+                let half_turn = $full_turn / 2.0;
+                let mut delta = (end.0 - self.0) % $full_turn;
+
+                match path {
+                    AnglePath::Shortest => {
+                        if delta > half_turn {
+                            delta -= $full_turn;
+                        } else if delta < -half_turn {
+                            delta += $full_turn;
+                        }
+                    }
+                    AnglePath::Clockwise => {
+                        if delta > 0.0 {
+                            delta -= $full_turn;
+                        }
+                    }
+                    AnglePath::CounterClockwise => {
+                        if delta < 0.0 {
+                            delta += $full_turn;
+                        }
+                    }
+                    AnglePath::Raw => return end,
+                }
+
+                Self(self.0 + delta)
+            }
+        }
+
+        impl Angle for $name {
+            #[inline]
+            fn wrapped(self) -> Self {
+                self.wrapped()
+            }
+
+            #[inline]
+            fn path_to(self, end: Self, path: AnglePath) -> Self {
+                self.path_to(end, path)
+            }
+        }
+
+        impl crate::TweenValue for $name {
+            #[inline(always)]
+            fn scale(self, scale: f32) -> Self {
+                Self(self.0 * scale)
+            }
+
+            #[inline(always)]
+            fn fraction_of(self, whole: Self) -> Option<f32> {
+                self.0.fraction_of(whole.0)
+            }
+
+            #[inline(always)]
+            fn is_finite(self) -> bool {
+                self.0.is_finite()
+            }
+        }
+
+        impl core::ops::Add for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl core::ops::Sub for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl core::ops::Neg for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+impl_angle!(Radians, TAU);
+impl_angle!(Degrees, 360.0);
+
+impl From<Degrees> for Radians {
+    fn from(value: Degrees) -> Self {
+        Self(value.0.to_radians())
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(value: Radians) -> Self {
+        Self(value.0.to_degrees())
+    }
+}
+
+impl<Value, Time, T> crate::Tweener<Value, Time, T>
+where
+    Value: Angle,
+    Time: crate::TweenTime,
+    T: crate::Tween<Value>,
+{
+    /// Creates a new Tweener which goes from the `start` angle to the `end` angle by `path`.
+    pub fn angle(start: Value, end: Value, duration: Time, tween: T, path: AnglePath) -> Self {
+        Self::new(start, start.path_to(end, path), duration, tween)
+    }
+
+    /// Creates a new Tweener which goes from the `start` angle to the `end` angle by `path`, at
+    /// the given time.
+    pub fn angle_at(start: Value, end: Value, duration: Time, tween: T, path: AnglePath, current_time: Time) -> Self {
+        Self::new_at(start, start.path_to(end, path), duration, tween, current_time)
+    }
+}
+
+impl<Value, Time, T> crate::FixedTweener<Value, Time, T>
+where
+    Value: Angle,
+    Time: crate::TweenTime,
+    T: crate::Tween<Value>,
+{
+    /// Creates a new FixedTweener which goes from the `start` angle to the `end` angle by `path`.
+    pub fn angle(start: Value, end: Value, duration: Time, tween: T, path: AnglePath, delta: Time) -> Self {
+        Self::new(start, start.path_to(end, path), duration, tween, delta)
+    }
+
+    /// Creates a new FixedTweener which goes from the `start` angle to the `end` angle by `path`,
+    /// at the given time.
+    pub fn angle_at(
+        start: Value,
+        end: Value,
+        duration: Time,
+        tween: T,
+        path: AnglePath,
+        current_time: Time,
+        delta: Time,
+    ) -> Self {
+        Self::new_at(start, start.path_to(end, path), duration, tween, current_time, delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Linear, Tweener};
+    use approx::assert_relative_eq;
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn paths() {
+        let start = Degrees(350.0);
+        let end = Degrees(10.0);
+
+        assert_eq!(start.path_to(end, AnglePath::Shortest), Degrees(370.0));
+        assert_eq!(start.path_to(end, AnglePath::CounterClockwise), Degrees(370.0));
+        assert_eq!(start.path_to(end, AnglePath::Clockwise), Degrees(10.0));
+        assert_eq!(start.path_to(end, AnglePath::Raw), Degrees(10.0));
+
+        assert_eq!(end.path_to(start, AnglePath::Shortest), Degrees(-10.0));
+        assert_eq!(end.path_to(start, AnglePath::Clockwise), Degrees(-10.0));
+        assert_eq!(end.path_to(start, AnglePath::CounterClockwise), Degrees(350.0));
+
+        // spun around a few times, we still only go the short way
+        assert_eq!(
+            Degrees(730.0).path_to(Degrees(-20.0), AnglePath::Shortest),
+            Degrees(700.0)
+        );
+        assert_eq!(
+            Degrees(90.0).path_to(Degrees(90.0), AnglePath::Clockwise),
+            Degrees(90.0)
+        );
+
+        let radians = Radians(0.1).path_to(Radians(-0.1 + FRAC_PI_2 * 4.0), AnglePath::Shortest);
+        assert_relative_eq!(radians.0, -0.1, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(Degrees(370.0).wrapped(), Degrees(10.0));
+        assert_eq!(Degrees(-10.0).wrapped(), Degrees(350.0));
+        assert_eq!(Degrees(360.0).wrapped(), Degrees(0.0));
+        assert_relative_eq!(Radians(-FRAC_PI_2).wrapped().0, PI * 1.5);
+
+        assert_relative_eq!(Radians::from(Degrees(180.0)).0, PI);
+        assert_relative_eq!(Degrees::from(Radians(FRAC_PI_2)).0, 90.0);
+    }
+
+    #[test]
+    fn tweeners() {
+        let mut tweener = Tweener::angle(Degrees(10.0), Degrees(350.0), 4, Linear, AnglePath::Shortest);
+        assert_eq!(tweener.move_to(2), Degrees(0.0));
+        assert_eq!(tweener.move_to(4), Degrees(-10.0));
+
+        let tweener = FixedTweener::angle(Degrees(0.0), Degrees(90.0), 2, Linear, AnglePath::Clockwise, 1);
+        assert!(tweener.eq([Degrees(-135.0), Degrees(-270.0)]));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_rotations() {
+        let direction = glam::Vec2::from(Radians(FRAC_PI_2));
+        assert!(direction.abs_diff_eq(glam::Vec2::Y, 0.0001));
+        assert_relative_eq!(Radians::from(glam::Vec2::NEG_X).0, PI);
+
+        let matrix = glam::Mat2::from(Radians(FRAC_PI_2));
+        assert!((matrix * glam::Vec2::X).abs_diff_eq(glam::Vec2::Y, 0.0001));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra_rotations() {
+        let rotation = nalgebra::UnitComplex::from(Radians(FRAC_PI_2));
+        assert_relative_eq!(rotation.angle(), FRAC_PI_2);
+        assert_relative_eq!(Radians::from(rotation).0, FRAC_PI_2);
+
+        let rotation = nalgebra::Rotation2::from(Radians(FRAC_PI_2));
+        assert_relative_eq!(Radians::from(rotation).0, FRAC_PI_2);
+    }
+}
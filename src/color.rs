@@ -0,0 +1,573 @@
+//! Color types which tween in perceptually sensible ways.
+//!
+//! Tweening the raw channels of an sRGB color gives muddy, dark midpoints, because sRGB is gamma
+//! encoded. Each type in this module implements [Interpolate], so it can be tweened with an
+//! [InterpolatingTweener](crate::InterpolatingTweener) in its own color space:
+//!
+//! - [Srgba] and [LinearRgba] interpolate in linear light, which blends like real light does.
+//! - [Oklab] interpolates in a perceptually uniform space, which keeps midpoints evenly bright and
+//!   saturated. This is usually the best choice for gradients.
+//! - [Oklch] and [Hsva] interpolate around a hue wheel, taking the shorter way around. Wrap them in
+//!   [LongerHue] to take the longer way, or use their `mix` methods with a [HuePath].
+//!
+//! All of these interpolate with premultiplied alpha, so fading to a transparent color doesn't
+//! darken or tint the midpoint.
+//!
+//! ```
+//! # use tween::{color::{Oklab, Srgba}, InterpolatingTweener, Linear};
+//! let red = Oklab::from(Srgba::from_u32(0xff0000ff));
+//! let blue = Oklab::from(Srgba::from_u32(0x0000ffff));
+//! let mut tweener = InterpolatingTweener::new(red, blue, 1.0, Linear);
+//!
+//! let purple = Srgba::from(tweener.move_to(0.5));
+//! assert_eq!(purple.to_u32(), 0x8c53a2ff);
+//! ```
+
+use crate::{Interpolate, math};
+
+/// How far from zero a chroma or saturation must be before its hue means anything.
+const HUE_EPSILON: f32 = 1e-4;
+
+/// A gamma encoded sRGB color with alpha, which is what most image editors and color pickers
+/// show. Each channel is usually between `0.0` and `1.0`.
+///
+/// An `Srgba` interpolates in linear light, by converting to [LinearRgba] and back.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+pub struct Srgba {
+    /// The red channel.
+    pub r: f32,
+    /// The green channel.
+    pub g: f32,
+    /// The blue channel.
+    pub b: f32,
+    /// The alpha channel, where `1.0` is opaque.
+    pub a: f32,
+}
+
+impl Srgba {
+    /// Creates a new color from its channels.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a new color from `u8` channels.
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+    }
+
+    /// Converts this color to `u8` channels, clamping each channel to `0.0..=1.0` first.
+    pub fn to_rgba8(self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a].map(|v| (v.clamp(0.0, 1.0) * 255.0 + 0.5) as u8)
+    }
+
+    /// Creates a new color from a packed `0xRRGGBBAA` value, like `0xff8800ff`.
+    pub fn from_u32(rgba: u32) -> Self {
+        let [r, g, b, a] = rgba.to_be_bytes();
+        Self::from_rgba8(r, g, b, a)
+    }
+
+    /// Converts this color to a packed `0xRRGGBBAA` value.
+    pub fn to_u32(self) -> u32 {
+        u32::from_be_bytes(self.to_rgba8())
+    }
+}
+
+impl Interpolate for Srgba {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        LinearRgba::interpolate(start.into(), end.into(), t).into()
+    }
+}
+
+/// A linear sRGB color with alpha, where each channel is proportional to the light it gives off.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+pub struct LinearRgba {
+    /// The red channel.
+    pub r: f32,
+    /// The green channel.
+    pub g: f32,
+    /// The blue channel.
+    pub b: f32,
+    /// The alpha channel, where `1.0` is opaque.
+    pub a: f32,
+}
+
+impl LinearRgba {
+    /// Creates a new color from its channels.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl Interpolate for LinearRgba {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        let ([r, g, b], a) = premultiplied_mix([start.r, start.g, start.b], start.a, [end.r, end.g, end.b], end.a, t);
+
+        Self { r, g, b, a }
+    }
+}
+
+/// A color in the Oklab space, which is perceptually uniform: equal steps in it look like equal
+/// changes in color.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+pub struct Oklab {
+    /// The perceived lightness, from `0.0` (black) to `1.0` (white).
+    pub l: f32,
+    /// How green (negative) or red (positive) the color is.
+    pub a: f32,
+    /// How blue (negative) or yellow (positive) the color is.
+    pub b: f32,
+    /// The alpha channel, where `1.0` is opaque.
+    pub alpha: f32,
+}
+
+impl Oklab {
+    /// Creates a new color from its channels.
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+}
+
+impl Interpolate for Oklab {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        let ([l, a, b], alpha) = premultiplied_mix(
+            [start.l, start.a, start.b],
+            start.alpha,
+            [end.l, end.a, end.b],
+            end.alpha,
+            t,
+        );
+
+        Self { l, a, b, alpha }
+    }
+}
+
+/// A color in the Oklch space, which is [Oklab] in polar coordinates: a lightness, a chroma
+/// (colorfulness), and a hue angle.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+pub struct Oklch {
+    /// The perceived lightness, from `0.0` (black) to `1.0` (white).
+    pub l: f32,
+    /// The chroma, from `0.0` (gray) up to about `0.4`.
+    pub c: f32,
+    /// The hue, in degrees.
+    pub h: f32,
+    /// The alpha channel, where `1.0` is opaque.
+    pub alpha: f32,
+}
+
+impl Oklch {
+    /// Creates a new color from its channels.
+    pub const fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+
+    /// Interpolates `t` of the way to `other`, going around the hue wheel by `path`.
+    pub fn mix(self, other: Self, t: f32, path: HuePath) -> Self {
+        let ([l, c], alpha) = premultiplied_mix([self.l, self.c], self.alpha, [other.l, other.c], other.alpha, t);
+        let h = mix_hue((self.h, self.c), (other.h, other.c), t, path);
+
+        Self { l, c, h, alpha }
+    }
+}
+
+impl Interpolate for Oklch {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start.mix(end, t, HuePath::Shorter)
+    }
+}
+
+/// A color in the HSV space, made of a hue angle, a saturation, and a value (brightness). This is
+/// the space most color pickers use.
+///
+/// HSV is built on top of gamma encoded sRGB, so it isn't perceptually uniform, but tweening
+/// around its hue wheel is a classic effect.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+pub struct Hsva {
+    /// The hue, in degrees.
+    pub h: f32,
+    /// The saturation, from `0.0` (gray) to `1.0`.
+    pub s: f32,
+    /// The value, from `0.0` (black) to `1.0`.
+    pub v: f32,
+    /// The alpha channel, where `1.0` is opaque.
+    pub a: f32,
+}
+
+impl Hsva {
+    /// Creates a new color from its channels.
+    pub const fn new(h: f32, s: f32, v: f32, a: f32) -> Self {
+        Self { h, s, v, a }
+    }
+
+    /// Interpolates `t` of the way to `other`, going around the hue wheel by `path`.
+    pub fn mix(self, other: Self, t: f32, path: HuePath) -> Self {
+        let ([s, v], a) = premultiplied_mix([self.s, self.v], self.a, [other.s, other.v], other.a, t);
+        let h = mix_hue((self.h, self.s.min(self.v)), (other.h, other.s.min(other.v)), t, path);
+
+        Self { h, s, v, a }
+    }
+}
+
+impl Interpolate for Hsva {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start.mix(end, t, HuePath::Shorter)
+    }
+}
+
+/// Which way around the hue wheel a color with a hue interpolates, matching CSS's
+/// `<hue-interpolation-method>`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub enum HuePath {
+    /// Take the shorter way around the wheel. This is the default.
+    #[default]
+    Shorter,
+    /// Take the longer way around the wheel.
+    Longer,
+    /// Always move in the direction of increasing hue.
+    Increasing,
+    /// Always move in the direction of decreasing hue.
+    Decreasing,
+}
+
+/// A wrapper around [Oklch] or [Hsva] which interpolates the longer way around the hue wheel.
+///
+/// ```
+/// # use tween::{color::{Hsva, LongerHue}, Interpolate};
+/// let red = Hsva::new(0.0, 1.0, 1.0, 1.0);
+/// let orange = Hsva::new(30.0, 1.0, 1.0, 1.0);
+///
+/// // the short way is just a little further towards orange...
+/// assert_eq!(Hsva::interpolate(red, orange, 0.5).h, 15.0);
+/// // ...and the long way goes through every other hue
+/// assert_eq!(
+///     LongerHue::interpolate(LongerHue(red), LongerHue(orange), 0.5)
+///         .0
+///         .h,
+///     195.0
+/// );
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct LongerHue<C>(pub C);
+
+impl Interpolate for LongerHue<Oklch> {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        Self(start.0.mix(end.0, t, HuePath::Longer))
+    }
+}
+
+impl Interpolate for LongerHue<Hsva> {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        Self(start.0.mix(end.0, t, HuePath::Longer))
+    }
+}
+
+impl From<Srgba> for LinearRgba {
+    fn from(value: Srgba) -> Self {
+        Self::new(
+            srgb_to_linear(value.r),
+            srgb_to_linear(value.g),
+            srgb_to_linear(value.b),
+            value.a,
+        )
+    }
+}
+
+impl From<LinearRgba> for Srgba {
+    fn from(value: LinearRgba) -> Self {
+        Self::new(
+            linear_to_srgb(value.r),
+            linear_to_srgb(value.g),
+            linear_to_srgb(value.b),
+            value.a,
+        )
+    }
+}
+
+// these are the coefficients as published, so we keep every digit
+#[allow(clippy::excessive_precision)]
+impl From<LinearRgba> for Oklab {
+    fn from(value: LinearRgba) -> Self {
+        let LinearRgba { r, g, b, a } = value;
+
+        let l = math::cbrt(0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b);
+        let m = math::cbrt(0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b);
+        let s = math::cbrt(0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b);
+
+        Self::new(
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+            a,
+        )
+    }
+}
+
+// these are the coefficients as published, so we keep every digit
+#[allow(clippy::excessive_precision)]
+impl From<Oklab> for LinearRgba {
+    fn from(value: Oklab) -> Self {
+        let Oklab { l, a, b, alpha } = value;
+
+        let cube = |v: f32| v * v * v;
+        let l_ = cube(l + 0.3963377774 * a + 0.2158037573 * b);
+        let m_ = cube(l - 0.1055613458 * a - 0.0638541728 * b);
+        let s_ = cube(l - 0.0894841775 * a - 1.2914855480 * b);
+
+        Self::new(
+            4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+            -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+            -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+            alpha,
+        )
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(value: Oklab) -> Self {
+        let c = math::sqrt(value.a * value.a + value.b * value.b);
+        let h = normalize_degrees(math::atan2(value.b, value.a).to_degrees());
+
+        Self::new(value.l, c, h, value.alpha)
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(value: Oklch) -> Self {
+        let h = value.h.to_radians();
+
+        Self::new(value.l, value.c * math::cos(h), value.c * math::sin(h), value.alpha)
+    }
+}
+
+impl From<Srgba> for Oklab {
+    fn from(value: Srgba) -> Self {
+        LinearRgba::from(value).into()
+    }
+}
+
+impl From<Oklab> for Srgba {
+    fn from(value: Oklab) -> Self {
+        LinearRgba::from(value).into()
+    }
+}
+
+impl From<Srgba> for Oklch {
+    fn from(value: Srgba) -> Self {
+        Oklab::from(value).into()
+    }
+}
+
+impl From<Oklch> for Srgba {
+    fn from(value: Oklch) -> Self {
+        Oklab::from(value).into()
+    }
+}
+
+impl From<Srgba> for Hsva {
+    fn from(value: Srgba) -> Self {
+        let Srgba { r, g, b, a } = value;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+
+        let h = if chroma == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / chroma)
+        } else if max == g {
+            60.0 * ((b - r) / chroma + 2.0)
+        } else {
+            60.0 * ((r - g) / chroma + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { chroma / max };
+
+        Self::new(normalize_degrees(h), s, max, a)
+    }
+}
+
+impl From<Hsva> for Srgba {
+    fn from(value: Hsva) -> Self {
+        let Hsva { h, s, v, a } = value;
+
+        let f = |n: f32| {
+            let k = (n + normalize_degrees(h) / 60.0) % 6.0;
+            v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+
+        Self::new(f(5.0), f(3.0), f(1.0), a)
+    }
+}
+
+/// Interpolates some color channels with premultiplied alpha, returning the new channels and
+/// alpha.
+fn premultiplied_mix<const N: usize>(
+    start: [f32; N],
+    start_alpha: f32,
+    end: [f32; N],
+    end_alpha: f32,
+    t: f32,
+) -> ([f32; N], f32) {
+    let alpha = lerp(start_alpha, end_alpha, t);
+
+    // a fully transparent color has no channels to speak of, so we blend them plainly
+    if alpha == 0.0 {
+        return (core::array::from_fn(|i| lerp(start[i], end[i], t)), alpha);
+    }
+
+    let channels = core::array::from_fn(|i| lerp(start[i] * start_alpha, end[i] * end_alpha, t) / alpha);
+
+    (channels, alpha)
+}
+
+/// Interpolates two hues, given with the chroma (or saturation) which they belong to. A gray has
+/// no meaningful hue, so we use the other color's hue instead.
+fn mix_hue((start, start_chroma): (f32, f32), (end, end_chroma): (f32, f32), t: f32, path: HuePath) -> f32 {
+    let start = normalize_degrees(start);
+    let end = normalize_degrees(end);
+
+    let (start, end) = match (start_chroma.abs() < HUE_EPSILON, end_chroma.abs() < HUE_EPSILON) {
+        (true, false) => (end, end),
+        (false, true) => (start, start),
+        _ => (start, end),
+    };
+
+    let mut delta = end - start;
+    match path {
+        HuePath::Shorter => {
+            if delta > 180.0 {
+                delta -= 360.0;
+            } else if delta < -180.0 {
+                delta += 360.0;
+            }
+        }
+        HuePath::Longer => {
+            if 0.0 < delta && delta < 180.0 {
+                delta -= 360.0;
+            } else if -180.0 < delta && delta <= 0.0 {
+                delta += 360.0;
+            }
+        }
+        HuePath::Increasing => {
+            if delta < 0.0 {
+                delta += 360.0;
+            }
+        }
+        HuePath::Decreasing => {
+            if delta > 0.0 {
+                delta -= 360.0;
+            }
+        }
+    }
+
+    normalize_degrees(start + delta * t)
+}
+
+fn normalize_degrees(degrees: f32) -> f32 {
+    let output = degrees % 360.0;
+    if output < 0.0 { output + 360.0 } else { output }
+}
+
+fn lerp(start: f32, end: f32, t: f32) -> f32 {
+    start + (end - start) * t
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    let magnitude = value.abs();
+    let output = if magnitude <= 0.04045 {
+        magnitude / 12.92
+    } else {
+        math::powf((magnitude + 0.055) / 1.055, 2.4)
+    };
+
+    output.copysign(value)
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+    let magnitude = value.abs();
+    let output = if magnitude <= 0.0031308 {
+        magnitude * 12.92
+    } else {
+        1.055 * math::powf(magnitude, 1.0 / 2.4) - 0.055
+    };
+
+    output.copysign(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn packed() {
+        let color = Srgba::from_u32(0x11_80_ff_40);
+        assert_eq!(color.to_rgba8(), [0x11, 0x80, 0xff, 0x40]);
+        assert_eq!(color.to_u32(), 0x1180ff40);
+        assert_eq!(Srgba::new(2.0, -1.0, 0.5, 1.0).to_u32(), 0xff0080ff);
+
+        for packed in [0x00000000, 0xffffffff, 0x12345678, 0xfedcba98] {
+            assert_eq!(Srgba::from(LinearRgba::from(Srgba::from_u32(packed))).to_u32(), packed);
+            assert_eq!(Srgba::from(Oklch::from(Srgba::from_u32(packed))).to_u32(), packed);
+            assert_eq!(Srgba::from(Hsva::from(Srgba::from_u32(packed))).to_u32(), packed);
+        }
+    }
+
+    #[test]
+    fn oklab_reference() {
+        // reference values from Björn Ottosson's post introducing Oklab
+        let white = Oklab::from(Srgba::new(1.0, 1.0, 1.0, 1.0));
+        assert_relative_eq!(white.l, 1.0, epsilon = 0.001);
+        assert_relative_eq!(white.a, 0.0, epsilon = 0.001);
+        assert_relative_eq!(white.b, 0.0, epsilon = 0.001);
+
+        let red = Oklch::from(Srgba::new(1.0, 0.0, 0.0, 1.0));
+        assert_relative_eq!(red.l, 0.628, epsilon = 0.001);
+        assert_relative_eq!(red.c, 0.2577, epsilon = 0.001);
+        assert_relative_eq!(red.h, 29.23, epsilon = 0.01);
+    }
+
+    #[test]
+    fn linear_light() {
+        // a plain channel lerp would give 0x808080
+        let black = Srgba::new(0.0, 0.0, 0.0, 1.0);
+        let white = Srgba::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(Srgba::interpolate(black, white, 0.5).to_u32(), 0xbcbcbcff);
+    }
+
+    #[test]
+    fn premultiplied_alpha() {
+        let red = LinearRgba::new(1.0, 0.0, 0.0, 1.0);
+        let clear = LinearRgba::new(0.0, 0.0, 0.0, 0.0);
+
+        // fading out doesn't darken the color
+        let halfway = LinearRgba::interpolate(red, clear, 0.5);
+        assert_eq!(halfway, LinearRgba::new(1.0, 0.0, 0.0, 0.5));
+
+        // and a nearly transparent color barely tints the other
+        let faint_blue = Oklab::from(Srgba::new(0.0, 0.0, 1.0, 0.01));
+        let red = Oklab::from(Srgba::new(1.0, 0.0, 0.0, 1.0));
+        let halfway = Srgba::from(Oklab::interpolate(red, faint_blue, 0.5));
+        assert!(halfway.r > 0.95 && halfway.b < 0.1);
+
+        assert_eq!(LinearRgba::interpolate(clear, clear, 0.5), clear);
+    }
+
+    #[test]
+    fn hue_paths() {
+        let a = Oklch::new(0.7, 0.1, 350.0, 1.0);
+        let b = Oklch::new(0.7, 0.1, 10.0, 1.0);
+
+        assert_relative_eq!(Oklch::interpolate(a, b, 0.5).h, 0.0, epsilon = 0.001);
+        assert_relative_eq!(LongerHue::interpolate(LongerHue(a), LongerHue(b), 0.5).0.h, 180.0);
+        assert_relative_eq!(a.mix(b, 0.25, HuePath::Increasing).h, 355.0);
+        assert_relative_eq!(a.mix(b, 0.5, HuePath::Decreasing).h, 180.0);
+        assert_relative_eq!(b.mix(a, 0.5, HuePath::Decreasing).h, 0.0, epsilon = 0.001);
+
+        // gray has no hue, so only the colorful end's hue is used
+        let gray = Hsva::new(0.0, 0.0, 0.5, 1.0);
+        let teal = Hsva::new(180.0, 1.0, 0.5, 1.0);
+        assert_eq!(Hsva::interpolate(gray, teal, 0.5).h, 180.0);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Tween, TweenValue};
+
+/// How many pieces we cut a tween into to look for where it first reaches a value. Tweens which
+/// cross a value and come back within one piece, like a very fast elastic, can be missed.
+const SAMPLES: usize = 64;
+
+/// How many bisection steps we take once we've found the piece the value is in. This is well
+/// below the precision of an `f32`.
+const BISECTION_ITERATIONS: usize = 32;
+
+/// Finds the first percent within `[0, 1]` at which `sample` reaches or passes `value`, by
+/// sampling and then bisection. This works for any tween, but it's only as good as the samples.
+pub(crate) fn solve<Value>(mut sample: impl FnMut(f32) -> Value, value: Value) -> Option<f32>
+where
+    Value: TweenValue + PartialOrd,
+{
+    let between = |a: Value, b: Value| (a <= value && value <= b) || (b <= value && value <= a);
+
+    let mut before = sample(0.0);
+    if before == value {
+        return Some(0.0);
+    }
+
+    for i in 1..=SAMPLES {
+        let (mut low, mut high) = ((i - 1) as f32 / SAMPLES as f32, i as f32 / SAMPLES as f32);
+        let after = sample(high);
+
+        if !between(before, after) {
+            before = after;
+            continue;
+        }
+
+        let mut low_value = before;
+        for _ in 0..BISECTION_ITERATIONS {
+            let middle = (low + high) / 2.0;
+            let middle_value = sample(middle);
+
+            if between(low_value, middle_value) {
+                high = middle;
+            } else {
+                low = middle;
+                low_value = middle_value;
+            }
+        }
+
+        return Some(high);
+    }
+
+    None
+}
+
+/// Inverts a tween which always moves towards its end. `inverse` maps how far along the tween is,
+/// from `0.0` to `1.0`, back to the percent where it gets there.
+///
+/// Values which aren't a simple fraction of the value delta, such as those which don't implement
+/// [TweenValue::fraction_of], fall back to [solve].
+#[inline]
+pub(crate) fn invert<Value, T>(
+    tween: &mut T,
+    value_delta: Value,
+    value: Value,
+    inverse: impl FnOnce(f32) -> f32,
+) -> Option<f32>
+where
+    Value: TweenValue + PartialOrd,
+    T: Tween<Value> + ?Sized,
+{
+    match value.fraction_of(value_delta) {
+        Some(progress) if (0.0..=1.0).contains(&progress) => Some(inverse(progress).clamp(0.0, 1.0)),
+        Some(_) => None,
+        None => solve(|percent| tween.tween(value_delta, percent), value),
+    }
+}
+
+/// Inverts an "in" easing which is `progress.powf(power)`, like [QuadIn](crate::QuadIn).
+#[inline]
+pub(crate) fn power_in(progress: f32, power: f32) -> f32 {
+    crate::math::powf(progress, 1.0 / power)
+}
+
+/// Inverts an "out" easing which is `1.0 - (1.0 - progress).powf(power)`, like
+/// [QuadOut](crate::QuadOut).
+#[inline]
+pub(crate) fn power_out(progress: f32, power: f32) -> f32 {
+    1.0 - crate::math::powf(1.0 - progress, 1.0 / power)
+}
+
+/// Inverts an "in out" easing which runs [power_in] and then [power_out], each over half the
+/// tween, like [QuadInOut](crate::QuadInOut).
+#[inline]
+pub(crate) fn power_in_out(progress: f32, power: f32) -> f32 {
+    if progress < 0.5 {
+        power_in(progress * 2.0, power) / 2.0
+    } else {
+        0.5 + power_out(progress * 2.0 - 1.0, power) / 2.0
+    }
+}
@@ -10,16 +10,23 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(all(feature = "std", feature = "libm"))]
 compile_error!("Please disable feature `libm` or disable default features -- both cannot be active at once.");
 
 #[macro_use]
 mod macros;
 
+mod angle;
+pub mod color;
+mod inverse;
 mod math;
 mod tweener;
 mod tweens;
 
+pub use angle::{Angle, AnglePath, Degrees, Radians};
 pub use tweener::*;
 pub use tweens::*;
 
@@ -50,6 +57,50 @@ pub trait Tween<Value> {
     fn is_finite(&self) -> bool {
         true
     }
+
+    /// Returns how fast the tween is changing at `percent`, as a value per unit of percent. This
+    /// is the derivative of [tween](Self::tween) with respect to `percent`.
+    ///
+    /// [Linear], for example, is always changing by `value_delta` per unit of percent. To get a
+    /// velocity in value per unit of time, use [Tweener::velocity].
+    ///
+    /// Every tween in this library implements this exactly. The default implementation, which
+    /// closures use, estimates it by running the tween just either side of `percent`.
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value
+    where
+        Value: TweenValue,
+    {
+        const STEP: f32 = 1.0 / 1024.0;
+
+        let (before, after) = if self.is_finite() {
+            ((percent - STEP).clamp(0.0, 1.0), (percent + STEP).clamp(0.0, 1.0))
+        } else {
+            (percent - STEP, percent + STEP)
+        };
+
+        if before >= after {
+            return value_delta.scale(0.0);
+        }
+
+        (self.tween(value_delta, after) - self.tween(value_delta, before)).scale(1.0 / (after - before))
+    }
+
+    /// Returns the first percent, from `0.0` to `1.0`, at which the tween reaches `value`, or
+    /// [None] if it never does. Tweens which jump, like [Steps], are counted as reaching every
+    /// value they jump past.
+    ///
+    /// [Linear], [QuadIn] and the other easings which only ever move towards their end solve this
+    /// exactly. Every other tween, including closures, uses the default implementation, which
+    /// samples the tween and then narrows in on the value. This finds the value as long as the
+    /// tween doesn't pass it and come back in under `1/64` of the tween.
+    ///
+    /// To find the *time* a [Tweener] reaches a value, use [Tweener::time_at_value].
+    fn inverse(&mut self, value_delta: Value, value: Value) -> Option<f32>
+    where
+        Value: TweenValue + PartialOrd,
+    {
+        inverse::solve(|percent| self.tween(value_delta, percent), value)
+    }
 }
 
 #[cfg(test)]
@@ -68,6 +119,19 @@ where
     fn is_finite(&self) -> bool {
         true
     }
+
+    #[inline(always)]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        (**self).derivative(value_delta, percent)
+    }
+
+    #[inline(always)]
+    fn inverse(&mut self, value_delta: Value, value: Value) -> Option<f32>
+    where
+        Value: PartialOrd,
+    {
+        (**self).inverse(value_delta, value)
+    }
 }
 
 impl_tween_for_box!();
@@ -101,6 +165,57 @@ pub trait TweenValue: Copy + core::fmt::Debug + core::ops::Add<Output = Self> +
     /// This should be implemented as a simple multiplication. For f64, for example,
     /// it's implemented as `(self as f32 * scale) as f64`.
     fn scale(self, scale: f32) -> Self;
+
+    /// Returns what fraction of `whole` this value is, which undoes [scale](Self::scale). This
+    /// should return [None] if `whole` is zero, or if there is no such fraction.
+    ///
+    /// This is used to solve [Tween::inverse] exactly, and defaults to [None], which falls back to
+    /// solving it numerically. Scalars, like `f32`, should implement it as `self / whole`.
+    #[inline(always)]
+    fn fraction_of(self, whole: Self) -> Option<f32> {
+        let _ = whole;
+        None
+    }
+
+    /// Returns `false` if this value is, or holds, an infinity or NaN.
+    ///
+    /// This is used to keep those from being carried into a [Tweener] by
+    /// [retarget](Tweener::retarget), and defaults to `true`. Floating point values should
+    /// implement it.
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        true
+    }
+}
+
+/// An `Interpolate` value can be moved between a start and an end, but not by adding a delta to
+/// the start like a [TweenValue].
+///
+/// Rotations are the common example: a quaternion halfway between two others is found by
+/// spherical interpolation (slerp), not by adding half of their difference. Values like these can
+/// be tweened with an [InterpolatingTweener], which uses any [Tween] to find how far along the
+/// tween is, and then this trait to find the value there.
+///
+/// This library implements `Interpolate` for `f32`, `f64`, and the rotation types of the math
+/// libraries behind its feature flags.
+pub trait Interpolate: Copy {
+    /// Returns the value `t` of the way from `start` to `end`, where `t` is usually between `0.0`
+    /// and `1.0`. Easings which overshoot, like [BackOut], will give values outside of that range.
+    fn interpolate(start: Self, end: Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    #[inline(always)]
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start + (end - start) * t
+    }
+}
+
+impl Interpolate for f64 {
+    #[inline(always)]
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start + (end - start) * t as f64
+    }
 }
 
 /// A `TweenTime` is a representation of Time. The two most common will be `f32`/`f64` for
@@ -123,6 +238,52 @@ pub trait TweenTime:
 
     /// Converts the given number to an `f32`.
     fn to_f32(self) -> f32;
+
+    /// Multiplies the given time by `scale`, like [Duration::mul_f32]. This is used to play
+    /// Tweeners at different speeds. See [Tweener::set_time_scale].
+    ///
+    /// For integer times this rounds towards zero, and a negative result saturates to `0` for
+    /// unsigned times.
+    ///
+    /// This method was added in a breaking release, so if you implement `TweenTime` for your own
+    /// type, you'll need to add it. Most times can convert through a float:
+    ///
+    /// ```
+    /// # use tween::TweenTime;
+    /// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    /// struct Frames(u32);
+    /// # impl core::ops::Add for Frames { type Output = Self; fn add(self, o: Self) -> Self { Self(self.0 + o.0) } }
+    /// # impl core::ops::AddAssign for Frames { fn add_assign(&mut self, o: Self) { self.0 += o.0 } }
+    /// # impl core::ops::Sub for Frames { type Output = Self; fn sub(self, o: Self) -> Self { Self(self.0 - o.0) } }
+    /// # impl core::ops::Rem for Frames { type Output = Self; fn rem(self, o: Self) -> Self { Self(self.0 % o.0) } }
+    ///
+    /// impl TweenTime for Frames {
+    ///     const ZERO: Self = Frames(0);
+    ///
+    ///     fn to_f32(self) -> f32 {
+    ///         self.0 as f32
+    ///     }
+    ///
+    ///     fn mul_f32(self, scale: f32) -> Self {
+    ///         Frames((self.0 as f32 * scale) as u32)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Frames(10).mul_f32(0.5), Frames(5));
+    /// ```
+    ///
+    /// [Duration::mul_f32]: core::time::Duration::mul_f32
+    fn mul_f32(self, scale: f32) -> Self;
+
+    /// Multiplies the given time by `scale` like [mul_f32](Self::mul_f32), but rounds integer
+    /// times up instead. This is used when a time must be *at or after* some point, such as in
+    /// [Tweener::time_at_value].
+    ///
+    /// By default, this is just `mul_f32`, which is right for times which don't round.
+    #[inline(always)]
+    fn mul_f32_ceil(self, scale: f32) -> Self {
+        self.mul_f32(scale)
+    }
 }
 
 declare_time!(u8, i8, i16, u16, i32, i64, u32, u64, i128, u128, usize, isize);
@@ -134,6 +295,11 @@ impl TweenTime for f32 {
     fn to_f32(self) -> f32 {
         self
     }
+
+    #[inline(always)]
+    fn mul_f32(self, scale: f32) -> Self {
+        self * scale
+    }
 }
 impl TweenTime for f64 {
     const ZERO: Self = 0.0;
@@ -142,6 +308,11 @@ impl TweenTime for f64 {
     fn to_f32(self) -> f32 {
         self as f32
     }
+
+    #[inline(always)]
+    fn mul_f32(self, scale: f32) -> Self {
+        self * scale as f64
+    }
 }
 
 declare_value!(u8, i8, i16, u16, i32, i64, u32, u64, i128, u128, usize, isize);
@@ -151,6 +322,16 @@ impl TweenValue for f32 {
     fn scale(self, scale: f32) -> Self {
         self * scale
     }
+
+    #[inline(always)]
+    fn fraction_of(self, whole: Self) -> Option<f32> {
+        (whole != 0.0).then(|| self / whole)
+    }
+
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
 }
 
 impl TweenValue for f64 {
@@ -158,6 +339,16 @@ impl TweenValue for f64 {
     fn scale(self, scale: f32) -> Self {
         (self as f32 * scale) as Self
     }
+
+    #[inline(always)]
+    fn fraction_of(self, whole: Self) -> Option<f32> {
+        (whole != 0.0).then(|| (self / whole) as f32)
+    }
+
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
 }
 
 #[cfg(test)]
@@ -10,6 +10,25 @@ macro_rules! declare_time {
             fn to_f32(self) -> f32 {
                 self as f32
             }
+
+            #[inline(always)]
+            fn mul_f32(self, scale: f32) -> Self {
+                (self as f32 * scale) as $t
+            }
+
+            #[inline(always)]
+            fn mul_f32_ceil(self, scale: f32) -> Self {
+                let scaled = self as f32 * scale;
+                let round = crate::math::round(scaled);
+
+                // a product within a float's precision of a whole number is that number, just with
+                // rounding error, rather than a real fraction past it
+                if (scaled - round).abs() <= f32::EPSILON * scaled.abs() {
+                    round as $t
+                } else {
+                    crate::math::ceil(scaled) as $t
+                }
+            }
         }
         )*
     };
@@ -25,6 +44,11 @@ macro_rules! declare_value {
             fn scale(self, scale: f32) -> Self {
                 (self as f32 * scale) as $t
             }
+
+            #[inline(always)]
+            fn fraction_of(self, whole: Self) -> Option<f32> {
+                (whole != 0).then(|| self as f32 / whole as f32)
+            }
         })*
     };
 }
@@ -43,9 +67,14 @@ macro_rules! declare_tween {
         pub fn $tweener_at_method_name:ident;
 
         $tween:item
+
+        $derivative:item
+
+        $inverse:item
     ) => {
         $(#[$struct_meta])*
         #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $name;
 
         impl $name {
@@ -57,6 +86,16 @@ macro_rules! declare_tween {
             /// Run the given Tween with a new time.
             #[inline(always)]
             $tween
+
+            /// Returns how fast the Tween is changing at the given time. See
+            /// [Tween::derivative](crate::Tween::derivative).
+            #[inline(always)]
+            $derivative
+
+            /// Returns the first percent at which the Tween reaches `value`. See
+            /// [Tween::inverse](crate::Tween::inverse).
+            #[inline(always)]
+            $inverse
         }
 
         impl<Value> $crate::Tween<Value> for $name
@@ -67,6 +106,19 @@ macro_rules! declare_tween {
             fn tween(&mut self, value_delta: Value, percent_time: f32) -> Value {
                 self.tween(value_delta, percent_time)
             }
+
+            #[inline(always)]
+            fn derivative(&mut self, value_delta: Value, percent_time: f32) -> Value {
+                self.derivative(value_delta, percent_time)
+            }
+
+            #[inline(always)]
+            fn inverse(&mut self, value_delta: Value, value: Value) -> Option<f32>
+            where
+                Value: PartialOrd,
+            {
+                self.inverse(value_delta, value)
+            }
         }
 
         impl<Value, Time> $crate::Tweener<Value, Time, $crate::$name>
@@ -105,8 +157,8 @@ macro_rules! declare_tween {
 
 macro_rules! impl_tween_for_box {
     ($($trait_bounds:ident),*) => {
-        #[cfg(feature = "std")]
-        impl<Value: TweenValue> Tween<Value> for std::boxed::Box<dyn Tween<Value> $(+ $trait_bounds)*> {
+        #[cfg(feature = "alloc")]
+        impl<Value: TweenValue> Tween<Value> for alloc::boxed::Box<dyn Tween<Value> $(+ $trait_bounds)*> {
             #[inline(always)]
             fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
                 (**self).tween(value_delta, percent)
@@ -115,6 +167,19 @@ macro_rules! impl_tween_for_box {
             fn is_finite(&self) -> bool {
                 (**self).is_finite()
             }
+
+            #[inline(always)]
+            fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+                (**self).derivative(value_delta, percent)
+            }
+
+            #[inline(always)]
+            fn inverse(&mut self, value_delta: Value, value: Value) -> Option<f32>
+            where
+                Value: PartialOrd,
+            {
+                (**self).inverse(value_delta, value)
+            }
         }
     };
 }
@@ -243,6 +308,36 @@ macro_rules! test_tween {
                     assert_relative_eq!([<$name InOut>].tween(5.0, 1.0), [<Ease $name>]::ease_in_out(5.0, 0.0, 5.0, 5.0), epsilon = 0.00001);
                 }
 
+                #[test]
+                fn t_derivative() {
+                    use $crate::tweens::assert_derivative_matches;
+
+                    assert_derivative_matches([<$name In>]);
+                    assert_derivative_matches([<$name Out>]);
+                    assert_derivative_matches([<$name InOut>]);
+                }
+
+                #[test]
+                fn t_inverse() {
+                    fn check(mut tween: impl $crate::Tween<f32>) {
+                        for i in 1..20 {
+                            let value = i as f32 / 20.0;
+                            let percent = tween.inverse(1.0, value).unwrap();
+
+                            assert_relative_eq!(tween.tween(1.0, percent), value, epsilon = 0.0001);
+                            assert_relative_eq!(tween.inverse(-4.0, -4.0 * value).unwrap(), percent, epsilon = 0.0001);
+                        }
+
+                        assert_eq!(tween.inverse(1.0, 0.0), Some(0.0));
+                        assert_eq!(tween.inverse(1.0, -2.0), None);
+                        assert_eq!(tween.inverse(1.0, 3.0), None);
+                    }
+
+                    check([<$name In>]);
+                    check([<$name Out>]);
+                    check([<$name InOut>]);
+                }
+
                 #[test]
                 fn t_in_out_rev() {
                     let mut tweener = $crate::Tweener::new(100.0, 0.0, 10.0, [<$name InOut>]);
@@ -3,3 +3,48 @@ mod glam;
 
 #[cfg(feature = "nalgebra")]
 mod na;
+
+/// Declares crate-private float functions which use `std` or `libm`, whichever is enabled.
+macro_rules! declare_float_fns {
+    ($($name:ident => $libm:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = "std")]
+            #[inline(always)]
+            pub(crate) fn $name(value: f32) -> f32 {
+                value.$name()
+            }
+
+            #[cfg(feature = "libm")]
+            #[inline(always)]
+            pub(crate) fn $name(value: f32) -> f32 {
+                libm::$libm(value)
+            }
+        )*
+    };
+}
+
+declare_float_fns!(sqrt => sqrtf, cbrt => cbrtf, ceil => ceilf, floor => floorf, round => roundf, exp => expf, log2 => log2f, sin => sinf, cos => cosf, asin => asinf, acos => acosf);
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub(crate) fn powf(value: f32, exponent: f32) -> f32 {
+    value.powf(exponent)
+}
+
+#[cfg(feature = "libm")]
+#[inline(always)]
+pub(crate) fn powf(value: f32, exponent: f32) -> f32 {
+    libm::powf(value, exponent)
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    y.atan2(x)
+}
+
+#[cfg(feature = "libm")]
+#[inline(always)]
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    libm::atan2f(y, x)
+}
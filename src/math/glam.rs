@@ -2,6 +2,10 @@ impl crate::TweenValue for glam::Vec2 {
     fn scale(self, scale: f32) -> Self {
         self * scale
     }
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
 }
 
 impl crate::TweenValue for glam::UVec2 {
@@ -20,12 +24,20 @@ impl crate::TweenValue for glam::DVec2 {
     fn scale(self, scale: f32) -> Self {
         (self.as_vec2() * scale).as_dvec2()
     }
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
 }
 
 impl crate::TweenValue for glam::Vec3 {
     fn scale(self, scale: f32) -> Self {
         self * scale
     }
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
 }
 
 impl crate::TweenValue for glam::UVec3 {
@@ -44,12 +56,20 @@ impl crate::TweenValue for glam::DVec3 {
     fn scale(self, scale: f32) -> Self {
         (self.as_vec3() * scale).as_dvec3()
     }
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
 }
 
 impl crate::TweenValue for glam::Vec4 {
     fn scale(self, scale: f32) -> Self {
         self * scale
     }
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
 }
 
 impl crate::TweenValue for glam::UVec4 {
@@ -68,46 +88,160 @@ impl crate::TweenValue for glam::DVec4 {
     fn scale(self, scale: f32) -> Self {
         (self.as_vec4() * scale).as_dvec4()
     }
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
 }
 
 impl crate::TweenValue for glam::Mat2 {
     fn scale(self, scale: f32) -> Self {
         self * scale
     }
+
+    fn is_finite(self) -> bool {
+        self.to_cols_array().iter().all(|component| component.is_finite())
+    }
 }
 
 impl crate::TweenValue for glam::Mat3 {
     fn scale(self, scale: f32) -> Self {
         self * scale
     }
+
+    fn is_finite(self) -> bool {
+        self.to_cols_array().iter().all(|component| component.is_finite())
+    }
 }
 
 impl crate::TweenValue for glam::Mat3A {
     fn scale(self, scale: f32) -> Self {
         self * scale
     }
+
+    fn is_finite(self) -> bool {
+        self.to_cols_array().iter().all(|component| component.is_finite())
+    }
 }
 
 impl crate::TweenValue for glam::Mat4 {
     fn scale(self, scale: f32) -> Self {
         self * scale
     }
+
+    fn is_finite(self) -> bool {
+        self.to_cols_array().iter().all(|component| component.is_finite())
+    }
 }
 
 impl crate::TweenValue for glam::DMat2 {
     fn scale(self, scale: f32) -> Self {
         self * scale as f64
     }
+
+    fn is_finite(self) -> bool {
+        self.to_cols_array().iter().all(|component| component.is_finite())
+    }
 }
 
 impl crate::TweenValue for glam::DMat3 {
     fn scale(self, scale: f32) -> Self {
         self * scale as f64
     }
+
+    fn is_finite(self) -> bool {
+        self.to_cols_array().iter().all(|component| component.is_finite())
+    }
 }
 
 impl crate::TweenValue for glam::DMat4 {
     fn scale(self, scale: f32) -> Self {
         self * scale as f64
     }
+
+    fn is_finite(self) -> bool {
+        self.to_cols_array().iter().all(|component| component.is_finite())
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! keyframe_value_float_vec {
+    ($($t:ty => $f:ty),*) => {
+        $(
+            impl crate::KeyframeValue for $t {
+                fn monotone_tangent(
+                    previous_slope: Self,
+                    previous_duration: f32,
+                    next_slope: Self,
+                    next_duration: f32,
+                ) -> Self {
+                    let previous = previous_slope.to_array();
+                    let next = next_slope.to_array();
+
+                    Self::from_array(core::array::from_fn(|i| {
+                        crate::tweener::monotone_tangent(
+                            previous[i] as f64,
+                            previous_duration as f64,
+                            next[i] as f64,
+                            next_duration as f64,
+                        ) as $f
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+keyframe_value_float_vec!(
+    glam::Vec2 => f32, glam::Vec3 => f32, glam::Vec4 => f32,
+    glam::DVec2 => f64, glam::DVec3 => f64, glam::DVec4 => f64
+);
+
+// integer vectors and matrices use flat tangents
+#[cfg(feature = "alloc")]
+mod keyframe_value_flat {
+    impl crate::KeyframeValue for glam::UVec2 {}
+    impl crate::KeyframeValue for glam::IVec2 {}
+    impl crate::KeyframeValue for glam::UVec3 {}
+    impl crate::KeyframeValue for glam::IVec3 {}
+    impl crate::KeyframeValue for glam::UVec4 {}
+    impl crate::KeyframeValue for glam::IVec4 {}
+    impl crate::KeyframeValue for glam::Mat2 {}
+    impl crate::KeyframeValue for glam::Mat3 {}
+    impl crate::KeyframeValue for glam::Mat3A {}
+    impl crate::KeyframeValue for glam::Mat4 {}
+    impl crate::KeyframeValue for glam::DMat2 {}
+    impl crate::KeyframeValue for glam::DMat3 {}
+    impl crate::KeyframeValue for glam::DMat4 {}
+}
+
+impl crate::Interpolate for glam::Quat {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start.slerp(end, t)
+    }
+}
+
+impl crate::Interpolate for glam::DQuat {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start.slerp(end, t as f64)
+    }
+}
+
+impl From<crate::Radians> for glam::Vec2 {
+    fn from(value: crate::Radians) -> Self {
+        Self::from_angle(value.0)
+    }
+}
+
+impl From<glam::Vec2> for crate::Radians {
+    fn from(value: glam::Vec2) -> Self {
+        Self(value.to_angle())
+    }
+}
+
+impl From<crate::Radians> for glam::Mat2 {
+    fn from(value: crate::Radians) -> Self {
+        Self::from_angle(value.0)
+    }
 }
@@ -5,6 +5,10 @@ where
     fn scale(self, scale: f32) -> Self {
         nalgebra::Vector2::new(self[0].scale(scale), self[1].scale(scale))
     }
+
+    fn is_finite(self) -> bool {
+        self.iter().all(|component| component.is_finite())
+    }
 }
 
 impl<T> crate::TweenValue for nalgebra::Vector3<T>
@@ -14,6 +18,10 @@ where
     fn scale(self, scale: f32) -> Self {
         nalgebra::Vector3::new(self[0].scale(scale), self[1].scale(scale), self[2].scale(scale))
     }
+
+    fn is_finite(self) -> bool {
+        self.iter().all(|component| component.is_finite())
+    }
 }
 
 impl<T> crate::TweenValue for nalgebra::Vector4<T>
@@ -28,4 +36,86 @@ where
             self[3].scale(scale),
         )
     }
+
+    fn is_finite(self) -> bool {
+        self.iter().all(|component| component.is_finite())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::KeyframeValue for nalgebra::Vector2<T>
+where
+    T: crate::KeyframeValue + nalgebra::ClosedAddAssign + nalgebra::ClosedSubAssign + nalgebra::Scalar,
+{
+    fn monotone_tangent(previous_slope: Self, previous_duration: f32, next_slope: Self, next_duration: f32) -> Self {
+        Self::from_fn(|i, _| T::monotone_tangent(previous_slope[i], previous_duration, next_slope[i], next_duration))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::KeyframeValue for nalgebra::Vector3<T>
+where
+    T: crate::KeyframeValue + nalgebra::ClosedAddAssign + nalgebra::ClosedSubAssign + nalgebra::Scalar,
+{
+    fn monotone_tangent(previous_slope: Self, previous_duration: f32, next_slope: Self, next_duration: f32) -> Self {
+        Self::from_fn(|i, _| T::monotone_tangent(previous_slope[i], previous_duration, next_slope[i], next_duration))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::KeyframeValue for nalgebra::Vector4<T>
+where
+    T: crate::KeyframeValue + nalgebra::ClosedAddAssign + nalgebra::ClosedSubAssign + nalgebra::Scalar,
+{
+    fn monotone_tangent(previous_slope: Self, previous_duration: f32, next_slope: Self, next_duration: f32) -> Self {
+        Self::from_fn(|i, _| T::monotone_tangent(previous_slope[i], previous_duration, next_slope[i], next_duration))
+    }
+}
+
+impl crate::Interpolate for nalgebra::UnitQuaternion<f32> {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start.slerp(&end, t)
+    }
+}
+
+impl crate::Interpolate for nalgebra::UnitQuaternion<f64> {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start.slerp(&end, t as f64)
+    }
+}
+
+impl crate::Interpolate for nalgebra::UnitComplex<f32> {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start.slerp(&end, t)
+    }
+}
+
+impl crate::Interpolate for nalgebra::UnitComplex<f64> {
+    fn interpolate(start: Self, end: Self, t: f32) -> Self {
+        start.slerp(&end, t as f64)
+    }
+}
+
+impl From<crate::Radians> for nalgebra::UnitComplex<f32> {
+    fn from(value: crate::Radians) -> Self {
+        Self::new(value.0)
+    }
+}
+
+impl From<nalgebra::UnitComplex<f32>> for crate::Radians {
+    fn from(value: nalgebra::UnitComplex<f32>) -> Self {
+        Self(value.angle())
+    }
+}
+
+impl From<crate::Radians> for nalgebra::Rotation2<f32> {
+    fn from(value: crate::Radians) -> Self {
+        Self::new(value.0)
+    }
+}
+
+impl From<nalgebra::Rotation2<f32>> for crate::Radians {
+    fn from(value: nalgebra::Rotation2<f32>) -> Self {
+        Self(value.angle())
+    }
 }
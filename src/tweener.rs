@@ -1,12 +1,48 @@
 use crate::{Tween, TweenTime, TweenValue};
 
+mod blend;
+mod bound;
+mod events;
 mod extrapolator;
+mod interpolating;
+#[cfg(feature = "alloc")]
+mod keyframe;
 mod looper;
+#[cfg(feature = "alloc")]
+mod manager;
+#[cfg(feature = "alloc")]
+mod mixer;
 mod oscillator;
+mod repeat;
+mod reverse;
+#[cfg(feature = "alloc")]
+mod sequence;
+#[cfg(feature = "alloc")]
+mod timeline;
+mod yoyo;
 
+pub use blend::{Blend, BlendWeight};
+pub use bound::{BoundTweener, Lens};
+pub use events::{TweenEvent, TweenEventKind, TweenEvents};
 pub use extrapolator::Extrapolator;
+pub use interpolating::InterpolatingTweener;
+#[cfg(all(feature = "alloc", feature = "glam"))]
+pub(crate) use keyframe::monotone_tangent;
+#[cfg(feature = "alloc")]
+pub use keyframe::{Interpolation, Keyframe, KeyframeTrack, KeyframeValue};
 pub use looper::Looper;
+#[cfg(feature = "alloc")]
+pub use manager::{BoxedTweener, TweenHandle, TweenManager};
+#[cfg(feature = "alloc")]
+pub use mixer::{BlendMode, LayerId, Mixer};
 pub use oscillator::Oscillator;
+pub use repeat::Repeat;
+pub use reverse::{Mirror, Reverse};
+#[cfg(feature = "alloc")]
+pub use sequence::{BoxedSequence, Sequence};
+#[cfg(feature = "alloc")]
+pub use timeline::{Position, Timeline, TimelineChild, TimelineId};
+pub use yoyo::Yoyo;
 
 /// A Tweener is a wrapper around a Tween. Although you can tween dynamically using just a raw
 /// Tween, this struct will manage state and allow for more naturalistic handling.
@@ -81,6 +117,29 @@ pub use oscillator::Oscillator;
 /// Finally, if you'd like this library to actually *not* clamp your tween, take a look at
 /// [Extrapolator].
 ///
+/// ## Playback
+///
+/// A Tweener can be paused with [pause], and sped up, slowed down, or played backwards with
+/// [set_time_scale]. These only affect [move_by], so `move_to` can still jump anywhere:
+///
+/// ```
+/// # use tween::{PlaybackDirection, Tweener};
+/// let mut tweener = Tweener::linear(0.0, 100.0, 10.0);
+/// tweener.set_time_scale(0.5);
+/// assert_eq!(tweener.move_by(2.0), 10.0);
+///
+/// tweener.pause();
+/// assert_eq!(tweener.move_by(2.0), 10.0);
+///
+/// tweener.resume();
+/// tweener.set_direction(PlaybackDirection::Backward);
+/// assert_eq!(tweener.move_by(2.0), 0.0);
+/// assert!(tweener.is_finished());
+/// ```
+///
+/// When playing backwards, [is_finished] means the Tweener has reached its *start*, and [move_by]
+/// won't take the current time below zero.
+///
 /// ## Iterator
 ///
 /// In situations where the same delta time is alwayas used for `move_by`, you can
@@ -91,7 +150,10 @@ pub use oscillator::Oscillator;
 /// [move_by]: Tweener::move_by
 /// [is_finished]: Tweener::is_finished
 /// [is_valid]: Tweener::is_valid
+/// [pause]: Tweener::pause
+/// [set_time_scale]: Tweener::set_time_scale
 #[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tweener<Value, Time, T: ?Sized> {
     /// The current time of the Tweener. You can change this value at will without running the
     /// Tween, or change it with `move_by`.
@@ -102,6 +164,8 @@ pub struct Tweener<Value, Time, T: ?Sized> {
 
     values: (Value, Value),
     value_delta: Value,
+    playback: Playback,
+    carried_velocity: Option<Value>,
 
     /// The actual underlying Tween.
     pub tween: T,
@@ -120,6 +184,8 @@ where
             values: (start, end),
             value_delta: end - start,
             duration,
+            playback: Playback::default(),
+            carried_velocity: None,
             tween,
             current_time: Time::ZERO,
         }
@@ -135,6 +201,8 @@ where
             values: (start, end),
             value_delta: end - start,
             duration,
+            playback: Playback::default(),
+            carried_velocity: None,
             tween,
             current_time,
         }
@@ -148,10 +216,24 @@ where
             duration: self.duration,
             values: self.values,
             value_delta: self.value_delta,
+            playback: self.playback,
+            carried_velocity: self.carried_velocity,
             tween: f(self.tween),
         }
     }
 
+    /// Wraps this Tweener's tween in a [Reverse], so it plays from its end value back to its start
+    /// value.
+    pub fn reversed(self) -> Tweener<Value, Time, Reverse<T>> {
+        self.map(Reverse)
+    }
+
+    /// Wraps this Tweener's tween in a [Mirror], so an `In` curve plays as its `Out`, and an `Out`
+    /// as its `In`.
+    pub fn mirrored(self) -> Tweener<Value, Time, Mirror<T>> {
+        self.map(Mirror)
+    }
+
     /// Moves the tween to a given Time. If this Tween previously was outside
     /// `0..=1` in parametric (percentage) space, ie. outside the duration of the tween or in
     /// negative time, this can move it back into bounds.
@@ -173,20 +255,196 @@ where
             }
         }
 
-        self.tween.tween(self.value_delta, pct) + self.values.0
+        self.sample(pct)
+    }
+
+    /// Returns the value at the given percent, without clamping.
+    #[inline(always)]
+    fn sample(&mut self, pct: f32) -> Value {
+        let value = self.tween.tween(self.value_delta, pct) + self.values.0;
+
+        match self.carried_velocity {
+            Some(velocity) => value + velocity.scale(carry_falloff(pct)),
+            None => value,
+        }
     }
 
     /// Drives the [Tweener] forward X steps in time.
     ///
     /// If an input higher than the tween's `duration` is given, you will
     /// receive the max value of the tween.
+    ///
+    /// The delta is first multiplied by the [time_scale], so a paused Tweener doesn't move at all,
+    /// and a Tweener playing backwards moves back towards its start. Playing backwards stops at
+    /// time zero, rather than going into negative time.
+    ///
+    /// Integer times can't hold a fraction of a step, so the part of a scaled delta which is
+    /// rounded away is kept and added to the next one. At a time scale of `0.5`, moving by `1`
+    /// moves a frame every other call, rather than never.
+    ///
+    /// [time_scale]: Self::time_scale
     #[inline]
     pub fn move_by(&mut self, delta: Time) -> Value {
-        self.current_time += delta;
+        let time_scale = self.time_scale();
+
+        if time_scale == 1.0 {
+            self.current_time += delta;
+            self.playback.remainder = 0.0;
+        } else if time_scale >= 0.0 {
+            self.current_time += self.playback.scale(delta, time_scale);
+        } else {
+            let step = self.playback.scale(delta, -time_scale);
+
+            if step <= self.current_time {
+                self.current_time = self.current_time - step;
+            } else if self.current_time > Time::ZERO {
+                self.current_time = Time::ZERO;
+                self.playback.remainder = 0.0;
+            }
+        }
 
         self.move_to(self.current_time)
     }
 
+    /// Returns how fast the Tweener's value is changing at its [current_time], in value per unit
+    /// of time. For example, a [Linear](crate::Linear) Tweener from `0.0` to `10.0` over `2.0`
+    /// seconds has a velocity of `5.0` the whole way.
+    ///
+    /// This is the velocity that [move_by] plays at, so it's multiplied by the [time_scale]: a
+    /// paused Tweener has no velocity, and a Tweener playing backwards has the opposite velocity.
+    /// A finite Tweener which is waiting to start or already finished isn't moving either.
+    ///
+    /// Every tween in this library knows its velocity exactly. Custom tweens and closures estimate
+    /// it; see [Tween::derivative]. The velocity isn't always finite, though: the circular easings,
+    /// like [CircOut](crate::CircOut), move infinitely fast where they meet a vertical edge of
+    /// their circle, so check the result before dividing by it or carrying it elsewhere.
+    ///
+    /// ```
+    /// # use tween::Tweener;
+    /// let mut tweener = Tweener::linear(0.0f32, 10.0, 2.0);
+    /// tweener.move_to(1.0);
+    /// assert_eq!(tweener.velocity(), 5.0);
+    ///
+    /// tweener.pause();
+    /// assert_eq!(tweener.velocity(), 0.0);
+    /// ```
+    ///
+    /// [current_time]: Self::current_time
+    /// [move_by]: Self::move_by
+    /// [time_scale]: Self::time_scale
+    pub fn velocity(&mut self) -> Value {
+        let pct = self.current_time.to_f32() / self.duration.to_f32();
+        if self.tween.is_finite() && (!(0.0..=1.0).contains(&pct) || self.is_finished()) {
+            return self.value_delta.scale(0.0);
+        }
+
+        let time_scale = self.time_scale();
+
+        self.percent_velocity(pct).scale(time_scale / self.duration.to_f32())
+    }
+
+    /// Returns the first time, from `0` to `duration`, at which the Tweener reaches `value`, or
+    /// [None] if it never does. This is useful for starting something else partway through a
+    /// tween, such as playing a sound once a fade is half done:
+    ///
+    /// ```
+    /// # use tween::{Linear, QuadIn, Tweener};
+    /// let mut tweener = Tweener::new(0.0f32, 1.0, 10.0, QuadIn);
+    /// assert_eq!(tweener.time_at_value(0.25), Some(5.0));
+    /// assert_eq!(tweener.time_at_value(2.0), None);
+    ///
+    /// // integer times round up, to the first frame which has reached the value
+    /// let mut tweener = Tweener::new(0, 100, 3, Linear);
+    /// assert_eq!(tweener.time_at_value(50), Some(2));
+    /// ```
+    ///
+    /// This uses [Tween::inverse], so it's exact for easings like [QuadIn](crate::QuadIn), and
+    /// found numerically for everything else. Tweens without bounds, like [Looper], are only
+    /// searched within their first `duration`. The time is found with [TweenTime::mul_f32_ceil],
+    /// so integer times round up.
+    pub fn time_at_value(&mut self, value: Value) -> Option<Time>
+    where
+        Value: PartialOrd,
+    {
+        let pct = if self.carried_velocity.is_some() {
+            // the carried velocity is ours rather than the tween's, so we have to solve it ourselves
+            crate::inverse::solve(|pct| self.sample(pct), value)
+        } else {
+            self.tween.inverse(self.value_delta, value - self.values.0)
+        }?;
+
+        Some(self.duration.mul_f32_ceil(pct))
+    }
+
+    /// Returns the speed that [move_by] plays at. This is `1.0` by default, and `0.0` while the
+    /// Tweener is [paused]. A negative time scale plays backwards.
+    ///
+    /// [move_by]: Self::move_by
+    /// [paused]: Self::is_paused
+    #[inline]
+    pub fn time_scale(&self) -> f32 {
+        if self.playback.paused {
+            0.0
+        } else {
+            self.playback.time_scale
+        }
+    }
+
+    /// Sets the speed that [move_by] plays at. For example, `2.0` plays twice as fast, `0.5` plays
+    /// in slow motion, and `-1.0` plays backwards.
+    ///
+    /// If the Tweener is paused, it keeps this time scale for when it's resumed. Any fraction of a
+    /// step which an integer time was holding on to is dropped, since it was kept at the old speed.
+    ///
+    /// [move_by]: Self::move_by
+    #[inline]
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.playback.time_scale = time_scale;
+        self.playback.remainder = 0.0;
+    }
+
+    /// Pauses the Tweener, so that [move_by](Self::move_by) leaves it where it is.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.playback.paused = true;
+    }
+
+    /// Resumes a paused Tweener at its previous time scale.
+    #[inline]
+    pub fn resume(&mut self) {
+        self.playback.paused = false;
+    }
+
+    /// Returns `true` if the Tweener is paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.playback.paused
+    }
+
+    /// Returns which way the Tweener plays, based on the sign of its time scale.
+    #[inline]
+    pub fn direction(&self) -> PlaybackDirection {
+        if self.playback.time_scale.is_sign_negative() {
+            PlaybackDirection::Backward
+        } else {
+            PlaybackDirection::Forward
+        }
+    }
+
+    /// Sets which way the Tweener plays, keeping the speed of its time scale. Like
+    /// [set_time_scale](Self::set_time_scale), this drops any fraction of a step an integer time
+    /// was holding on to, so that time kept going one way isn't spent going the other.
+    #[inline]
+    pub fn set_direction(&mut self, direction: PlaybackDirection) {
+        let speed = self.playback.time_scale.abs();
+        self.playback.remainder = 0.0;
+
+        self.playback.time_scale = match direction {
+            PlaybackDirection::Forward => speed,
+            PlaybackDirection::Backward => -speed,
+        };
+    }
+
     /// The initial value a tween was set to start at.
     #[inline]
     pub fn initial_value(&self) -> Value {
@@ -199,6 +457,100 @@ where
         self.values.1
     }
 
+    /// Sets the value the tween starts at, leaving the current time as it is.
+    pub fn set_initial_value(&mut self, start: Value) {
+        self.values.0 = start;
+        self.value_delta = self.values.1 - start;
+    }
+
+    /// Sets the value the tween ends at, leaving the current time as it is. This jumps straight to
+    /// the new curve, so to move there smoothly mid-tween, use [retarget](Self::retarget).
+    pub fn set_final_value(&mut self, end: Value) {
+        self.values.1 = end;
+        self.value_delta = end - self.values.0;
+    }
+
+    /// Sends the Tweener to a new end value, starting again from where it is now.
+    ///
+    /// The Tweener restarts at time zero, with its current value as its new start and
+    /// `new_duration` as its duration. Rather than starting from rest, it carries its current
+    /// velocity into the new motion, so there's no kink where it changed course. That velocity
+    /// fades out over the new duration, and the Tweener still lands exactly on `new_end`:
+    ///
+    /// ```
+    /// # use tween::Tweener;
+    /// let mut tweener = Tweener::sine_in_out(0.0f32, 100.0, 10.0);
+    /// let value = tweener.move_to(5.0);
+    ///
+    /// // halfway there, the destination moves
+    /// tweener.retarget(-50.0, 10.0);
+    /// assert_eq!(tweener.move_to(0.0), value);
+    /// assert!(tweener.move_to(1.0) > value); // still heading the old way for a moment...
+    /// assert_eq!(tweener.move_to(10.0), -50.0); // ...but it gets there in the end
+    /// ```
+    ///
+    /// A Tweener which is waiting to start or already finished isn't moving, so it starts the new
+    /// motion from rest. So does a Tweener whose velocity isn't finite, such as a
+    /// [CircOut](crate::CircOut), which starts infinitely fast.
+    ///
+    /// A `new_duration` of zero jumps straight to `new_end`, leaving the Tweener finished there.
+    ///
+    /// Retargeting always plays forward towards `new_end`, so a Tweener playing
+    /// [backward](PlaybackDirection::Backward) is switched to playing forward at the same speed,
+    /// still carrying the velocity it was moving at.
+    pub fn retarget(&mut self, new_end: Value, new_duration: Time) {
+        // a backward Tweener moves against its tween, so its velocity flips along with it
+        let direction = match self.direction() {
+            PlaybackDirection::Forward => 1.0,
+            PlaybackDirection::Backward => -1.0,
+        };
+        self.set_direction(PlaybackDirection::Forward);
+
+        if new_duration == Time::ZERO {
+            // we keep the old duration, so the Tweener has a finished time to sit at
+            self.values = (new_end, new_end);
+            self.value_delta = self.value_delta.scale(0.0);
+            self.current_time = self.duration;
+            self.carried_velocity = None;
+            return;
+        }
+
+        let old_duration = self.duration.to_f32();
+        let pct = self.current_time.to_f32() / old_duration;
+
+        let start = self.move_to(self.current_time);
+        let moving = !self.tween.is_finite() || (0.0..1.0).contains(&pct);
+
+        // the velocity per unit of *percent* depends on the duration, so we convert it from the
+        // old duration to the new one
+        let velocity = moving.then(|| {
+            self.percent_velocity(pct)
+                .scale(direction * new_duration.to_f32() / old_duration)
+        });
+
+        self.values = (start, new_end);
+        self.value_delta = new_end - start;
+        self.duration = new_duration;
+        self.current_time = Time::ZERO;
+
+        // the tween already starts with some velocity of its own, so we only carry the rest. we
+        // clear the old carry first, so that it isn't counted as part of the tween's velocity.
+        self.carried_velocity = None;
+        self.carried_velocity = velocity
+            .map(|velocity| velocity - self.percent_velocity(0.0))
+            .filter(|carried| carried.is_finite());
+    }
+
+    /// Returns the velocity at the given percent, in value per unit of percent.
+    fn percent_velocity(&mut self, pct: f32) -> Value {
+        let velocity = self.tween.derivative(self.value_delta, pct);
+
+        match self.carried_velocity {
+            Some(carried) => velocity + carried.scale(carry_falloff_slope(pct)),
+            None => velocity,
+        }
+    }
+
     /// Returns `true` is the Tweener's [current_time] is greater than or equal to `0`. Only
     /// negative times will return `false`.
     ///
@@ -214,14 +566,23 @@ where
 
     /// Returns `true` is the Tweener's [current_time] is greater than or equal to `duration`.
     ///
+    /// When playing [backward], this instead returns `true` if the [current_time] is less than or
+    /// equal to `0`, since that's where the Tweener ends in that direction.
+    ///
     /// Note that for tweens without bounds (infinite tweens like [Looper]), this method will always
     /// return `false`. Moreover, this method does not check if a tweener is *started*. For
     /// that, use [is_started].
     ///
     /// [current_time]: Self::current_time
     /// [is_started]: Self::is_started
+    /// [backward]: PlaybackDirection::Backward
     pub fn is_finished(&self) -> bool {
-        self.current_time_state() == CurrentTimeState::Finished
+        match self.direction() {
+            PlaybackDirection::Forward => self.current_time_state() == CurrentTimeState::Finished,
+            PlaybackDirection::Backward => {
+                self.tween.is_finite() && self.current_time.to_f32() / self.duration.to_f32() <= 0.0
+            }
+        }
     }
 
     /// Returns `true` is the Tweener's [current_time] is greater than or equal to `0` but less than
@@ -259,6 +620,12 @@ where
         }
     }
 
+    /// Binds this [Tweener] to a [Lens], making a [BoundTweener] which writes its value into a
+    /// target whenever it moves. See its documentation for more information.
+    pub fn bind<L>(self, lens: L) -> BoundTweener<Value, Time, T, L> {
+        BoundTweener::new(self, lens)
+    }
+
     /// Converts this [Tweener] to a [FixedTweener]. See its documentation for more information.
     pub fn into_fixed(self, delta: Time) -> FixedTweener<Value, Time, T> {
         FixedTweener::from_tweener(self, delta)
@@ -296,6 +663,10 @@ where
 ///
 /// If you *don't* want this behavior, you can instead use `move_next()`, which clamps.
 ///
+/// The iterator also returns `None` while the tweener is [paused](Tweener::pause) or has a
+/// [time scale](Tweener::set_time_scale) of `0.0`, since it wouldn't move. Once it's resumed,
+/// the iterator picks up where it left off.
+///
 /// ```
 /// # use tween::{FixedTweener, Tweener, Linear};
 /// // a single iteration length tween
@@ -312,6 +683,7 @@ where
 /// assert_eq!(fixed_tweener.move_next(), 1);
 /// ```
 #[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedTweener<Value, Time, T: ?Sized> {
     /// The delta upon which we move.
     pub delta: Time,
@@ -381,11 +753,17 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.tweener.is_valid() {
-            Some(self.move_next())
-        } else {
-            None
+        // a stopped tweener would otherwise return the same value forever
+        if self.tweener.time_scale() == 0.0 {
+            return None;
         }
+
+        let playing = match self.tweener.direction() {
+            PlaybackDirection::Forward => self.tweener.is_valid(),
+            PlaybackDirection::Backward => self.tweener.is_started() && !self.tweener.is_finished(),
+        };
+
+        if playing { Some(self.move_next()) } else { None }
     }
 }
 
@@ -393,6 +771,7 @@ where
 /// It returns `Waiting` is the current time is less than 0, `Finished` if it's at the duration of
 /// the [Tweener] or greater, and valid otherwise.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CurrentTimeState {
     /// Indicates the Tweener's current time was before `0`
     Waiting,
@@ -402,6 +781,100 @@ pub enum CurrentTimeState {
     Finished,
 }
 
+/// How much of a [retarget](Tweener::retarget)ed Tweener's carried velocity is left at the given
+/// percent. This is the cubic Hermite basis which starts with a slope of one, and is flat and zero
+/// at the end.
+#[inline(always)]
+fn carry_falloff(pct: f32) -> f32 {
+    let pct = pct.clamp(0.0, 1.0);
+    let remaining = 1.0 - pct;
+
+    pct * remaining * remaining
+}
+
+/// The slope of [carry_falloff] at the given percent.
+#[inline(always)]
+fn carry_falloff_slope(pct: f32) -> f32 {
+    if !(0.0..=1.0).contains(&pct) {
+        return 0.0;
+    }
+
+    (1.0 - pct) * (1.0 - 3.0 * pct)
+}
+
+/// Which way a [Tweener] plays when moved with [move_by](Tweener::move_by).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum PlaybackDirection {
+    /// Playing from the start towards the end. This is the default.
+    #[default]
+    Forward,
+    /// Playing from the end back towards the start.
+    Backward,
+}
+
+/// The playback state of a [Tweener].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Playback {
+    time_scale: f32,
+    paused: bool,
+
+    /// The part of the last scaled delta which was rounded away, to be added to the next one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    remainder: f32,
+}
+
+impl Playback {
+    /// Multiplies `delta` by `scale`, carrying whatever an integer time rounds away over to the
+    /// next call.
+    fn scale<Time: TweenTime>(&mut self, delta: Time, scale: f32) -> Time {
+        let delta_f32 = delta.to_f32();
+        if scale == 0.0 || delta_f32 == 0.0 {
+            return delta.mul_f32(scale);
+        }
+
+        let exact = delta_f32 * scale + self.remainder;
+        let step = delta.mul_f32(exact / delta_f32);
+        self.remainder = exact - step.to_f32();
+
+        step
+    }
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            time_scale: 1.0,
+            paused: false,
+            remainder: 0.0,
+        }
+    }
+}
+
+// these are written out so that a `Tweener` can still be `Eq` and `Ord`
+impl PartialEq for Playback {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == core::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Playback {}
+
+impl PartialOrd for Playback {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Playback {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.time_scale
+            .total_cmp(&other.time_scale)
+            .then(self.paused.cmp(&other.paused))
+            .then(self.remainder.total_cmp(&other.remainder))
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod tests {
@@ -487,6 +960,359 @@ mod tests {
         checker(Tweener::new(0, 2, 2, BounceOut));
     }
 
+    #[test]
+    fn playback() {
+        let mut tweener = Tweener::new(0, 100, 10, Linear);
+        tweener.set_time_scale(2.0);
+        assert_eq!(tweener.move_by(1), 20);
+
+        tweener.pause();
+        assert_eq!(tweener.time_scale(), 0.0);
+        assert_eq!(tweener.move_by(1), 20);
+        assert!(tweener.is_paused());
+
+        tweener.resume();
+        tweener.set_direction(PlaybackDirection::Backward);
+        assert_eq!(tweener.time_scale(), -2.0);
+        assert_eq!(tweener.move_by(1), 0);
+        assert!(tweener.is_finished());
+
+        // we stop at zero, even with unsigned time
+        assert_eq!(tweener.move_by(5), 0);
+        assert_eq!(tweener.current_time, 0);
+
+        let mut tweener = Tweener::new(0.0f32, 10.0, 10u32, Linear);
+        tweener.set_time_scale(-1.0);
+        assert_eq!(tweener.move_by(3), 0.0);
+        assert_eq!(tweener.current_time, 0);
+    }
+
+    #[test]
+    fn integer_time_scale() {
+        // integer times keep the fractions they round away, so slow motion still moves
+        let mut tweener = Tweener::new(0, 100, 10, Linear);
+        tweener.set_time_scale(0.5);
+        let times: std::vec::Vec<_> = (0..6)
+            .map(|_| {
+                tweener.move_by(1);
+                tweener.current_time
+            })
+            .collect();
+        assert_eq!(times, [0, 1, 1, 2, 2, 3]);
+
+        tweener.set_time_scale(-0.25);
+        for _ in 0..4 {
+            tweener.move_by(1);
+        }
+        assert_eq!(tweener.current_time, 2);
+
+        // pausing doesn't spend what's been kept
+        let mut tweener = Tweener::new(0, 100, 10u32, Linear);
+        tweener.set_time_scale(0.5);
+        tweener.move_by(1);
+        tweener.pause();
+        tweener.move_by(1);
+        assert_eq!(tweener.current_time, 0);
+        tweener.resume();
+        tweener.move_by(1);
+        assert_eq!(tweener.current_time, 1);
+
+        // flipping direction drops what's been kept, rather than spending it the other way
+        let mut tweener = Tweener::new(0, 100, 10, Linear);
+        tweener.move_to(4);
+        tweener.set_time_scale(0.5);
+        tweener.move_by(1);
+        assert_eq!(tweener.current_time, 4);
+        tweener.set_direction(PlaybackDirection::Backward);
+        tweener.move_by(1);
+        assert_eq!(tweener.current_time, 4);
+        tweener.move_by(1);
+        assert_eq!(tweener.current_time, 3);
+
+        tweener.move_by(1);
+        tweener.set_direction(PlaybackDirection::Forward);
+        tweener.move_by(1);
+        assert_eq!(tweener.current_time, 3);
+        tweener.move_by(1);
+        assert_eq!(tweener.current_time, 4);
+
+        // and so does changing speed
+        tweener.move_by(1);
+        tweener.set_time_scale(1.0);
+        tweener.set_time_scale(0.5);
+        tweener.move_by(1);
+        assert_eq!(tweener.current_time, 4);
+
+        // float times don't need to round at all
+        let mut tweener = Tweener::new(0.0f32, 1.0, 1.0, Linear);
+        tweener.set_time_scale(0.5);
+        tweener.move_by(0.25);
+        assert_eq!(tweener.current_time, 0.125);
+    }
+
+    #[test]
+    fn retarget() {
+        use crate::{CubicInOut, QuadIn};
+
+        fn velocity<T: Tween<f32>>(tweener: &mut Tweener<f32, f32, T>, time: f32) -> f32 {
+            (tweener.move_to(time + 0.001) - tweener.move_to(time - 0.001)) / 0.002
+        }
+
+        let mut tweener = Tweener::new(0.0f32, 100.0, 4.0, CubicInOut);
+        let before = velocity(&mut tweener, 1.5);
+        let value = tweener.move_to(1.5);
+
+        tweener.retarget(20.0, 2.0);
+        assert_eq!(tweener.current_time, 0.0);
+        assert_eq!(tweener.initial_value(), value);
+        assert_eq!(tweener.move_to(0.0), value);
+        assert!((velocity(&mut tweener, 0.001) - before).abs() < 0.5);
+        assert_eq!(tweener.move_to(2.0), 20.0);
+
+        // retargeting again carries the blended velocity along too
+        let before = velocity(&mut tweener, 0.5);
+        let value = tweener.move_to(0.5);
+        tweener.retarget(50.0, 1.0);
+        approx::assert_relative_eq!(tweener.move_to(0.0), value);
+        assert!((velocity(&mut tweener, 0.001) - before).abs() < 0.5);
+        assert_eq!(tweener.move_to(1.0), 50.0);
+
+        // a finished tweener isn't moving, so its new motion starts from rest
+        let mut tweener = Tweener::new(0.0f32, 10.0, 1.0, QuadIn);
+        tweener.move_to(2.0);
+        tweener.retarget(0.0, 1.0);
+        assert_eq!(tweener.move_to(0.5), 10.0 - QuadIn.tween(10.0, 0.5));
+    }
+
+    #[test]
+    fn retarget_edge_cases() {
+        use crate::{CircIn, CircOut};
+
+        // a CircOut starts infinitely fast, so it can't carry its velocity into itself
+        let mut tweener = Tweener::new(0.0f32, 100.0, 1.0, CircOut);
+        tweener.retarget(50.0, 1.0);
+        assert!(tweener.move_to(0.5).is_finite());
+        assert_eq!(tweener.move_to(0.5), CircOut.tween(50.0, 0.5));
+        assert_eq!(tweener.move_to(1.0), 50.0);
+
+        // nor can anything else, since the retargeted motion starts there too
+        let mut tweener = Tweener::new(0.0f32, 100.0, 1.0, CircIn);
+        tweener.move_to(0.5);
+        tweener.retarget(0.0, 1.0);
+        assert!(tweener.move_to(0.5).is_finite());
+        assert_eq!(tweener.move_to(1.0), 0.0);
+
+        // a zero duration jumps straight to the end
+        let mut tweener = Tweener::new(0.0f32, 100.0, 1.0, CircIn);
+        tweener.move_to(0.5);
+        tweener.retarget(30.0, 0.0);
+        assert!(tweener.is_finished());
+        assert_eq!(tweener.move_by(0.0), 30.0);
+        assert_eq!(tweener.move_to(0.0), 30.0);
+
+        let mut tweener = Tweener::new(0, 100, 10, CircIn);
+        tweener.move_to(5);
+        tweener.retarget(30, 0);
+        assert_eq!(tweener.move_by(1), 30);
+    }
+
+    #[test]
+    fn retarget_backward() {
+        let mut tweener = Tweener::linear(0.0f32, 10.0, 4.0);
+        tweener.move_to(4.0);
+        tweener.set_direction(PlaybackDirection::Backward);
+        assert_eq!(tweener.move_by(1.0), 7.5);
+        assert_eq!(tweener.velocity(), -2.5);
+
+        // the new motion plays forward, starting off in the direction it was already going
+        tweener.retarget(20.0, 4.0);
+        assert_eq!(tweener.direction(), PlaybackDirection::Forward);
+        assert!(!tweener.is_finished());
+        approx::assert_relative_eq!(tweener.velocity(), -2.5, epsilon = 0.0001);
+        assert_eq!(tweener.move_by(0.0), 7.5);
+
+        let value = tweener.move_by(1.0);
+        assert!(value != 7.5);
+        assert_eq!(tweener.move_by(3.0), 20.0);
+        assert!(tweener.is_finished());
+
+        // a zero duration retarget is finished going forward, too
+        let mut tweener = Tweener::linear(0.0f32, 10.0, 4.0);
+        tweener.move_to(2.0);
+        tweener.set_direction(PlaybackDirection::Backward);
+        tweener.retarget(30.0, 0.0);
+        assert!(tweener.is_finished());
+        assert_eq!(tweener.move_by(1.0), 30.0);
+    }
+
+    #[test]
+    fn velocity() {
+        use crate::{Looper, QuadIn};
+
+        let mut tweener = Tweener::new(0.0f32, 10.0, 2.0, QuadIn);
+        tweener.move_to(1.0);
+        approx::assert_relative_eq!(tweener.velocity(), 5.0);
+
+        tweener.set_time_scale(-0.5);
+        approx::assert_relative_eq!(tweener.velocity(), -2.5);
+        tweener.pause();
+        assert_eq!(tweener.velocity(), 0.0);
+
+        tweener.resume();
+        tweener.move_to(3.0);
+        assert_eq!(tweener.velocity(), 0.0);
+
+        // unbounded tweens keep moving
+        let mut tweener = Tweener::new(0.0f32, 10.0, 2.0, Looper::new(QuadIn));
+        tweener.move_to(3.0);
+        approx::assert_relative_eq!(tweener.velocity(), 5.0);
+
+        // closures estimate it
+        let mut tweener = Tweener::new(0.0f32, 10.0, 2.0, |delta: f32, pct: f32| delta * pct * pct);
+        tweener.move_to(1.0);
+        approx::assert_relative_eq!(tweener.velocity(), 5.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn unbounded_velocity() {
+        use crate::{CircIn, CircOut};
+
+        // a CircOut starts infinitely fast...
+        let mut tweener = Tweener::new(0.0f32, 100.0, 1.0, CircOut);
+        assert!(tweener.velocity().is_infinite());
+        tweener.move_to(0.5);
+        assert!(tweener.velocity().is_finite());
+
+        // ...and a CircIn is very fast just before it ends, but not once it's finished
+        let mut tweener = Tweener::new(0.0f32, 100.0, 1.0, CircIn);
+        tweener.move_to(0.9999);
+        assert!(tweener.velocity() > 5000.0);
+        tweener.move_to(1.0);
+        assert_eq!(tweener.velocity(), 0.0);
+
+        // retargeting carries that speed along, and still lands on the new end
+        tweener.move_to(0.9999);
+        tweener.retarget(0.0, 1.0);
+        assert!(tweener.velocity().is_finite());
+        assert_eq!(tweener.move_to(1.0), 0.0);
+    }
+
+    #[test]
+    fn time_at_value() {
+        use crate::{ElasticOut, QuadInOut};
+
+        let mut tweener = Tweener::new(10.0f32, 20.0, 4.0, QuadInOut);
+        assert_eq!(tweener.time_at_value(10.0), Some(0.0));
+        assert_eq!(tweener.time_at_value(15.0), Some(2.0));
+        assert_eq!(tweener.time_at_value(20.0), Some(4.0));
+        assert_eq!(tweener.time_at_value(9.0), None);
+
+        // going down works too
+        let mut tweener = Tweener::new(0, -100, 10, Linear);
+        assert_eq!(tweener.time_at_value(-30), Some(3));
+        assert_eq!(tweener.time_at_value(-25), Some(3));
+        assert_eq!(tweener.move_to(3), -30);
+
+        // integer times round up, so the value has been reached by then
+        let mut tweener = Tweener::new(0u32, 100, 3, Linear);
+        assert_eq!(tweener.time_at_value(50), Some(2));
+        assert_eq!(tweener.time_at_value(0), Some(0));
+        assert_eq!(tweener.time_at_value(100), Some(3));
+        assert!(tweener.move_to(2) >= 50);
+
+        // even with durations too long for an f32 to hold every integer, like microseconds
+        let mut tweener = Tweener::new(0u32, 10_000_000, 10_000_000u32, Linear);
+        let time = tweener.time_at_value(3_000_000).unwrap();
+        assert_eq!(time, 3_000_000);
+        assert!(tweener.move_to(time) >= 3_000_000);
+
+        let time = tweener.time_at_value(1_234_567).unwrap();
+        assert!(tweener.move_to(time) >= 1_234_567);
+
+        // the first time an overshooting tween gets there
+        let mut tweener = Tweener::new(0.0f32, 1.0, 1.0, ElasticOut);
+        let time = tweener.time_at_value(1.0).unwrap();
+        assert!(time < 0.2);
+        approx::assert_relative_eq!(tweener.move_to(time), 1.0, epsilon = 0.0001);
+
+        // a closure, and a retargeted tween, are found numerically
+        let mut tweener = Tweener::new(0.0f32, 8.0, 2.0, |delta: f32, pct: f32| delta * pct * pct * pct);
+        approx::assert_relative_eq!(tweener.time_at_value(1.0).unwrap(), 1.0, epsilon = 0.0001);
+
+        let mut tweener = Tweener::new(0.0f32, 10.0, 2.0, Linear);
+        tweener.move_to(1.0);
+        tweener.retarget(0.0, 1.0);
+        let time = tweener.time_at_value(2.0).unwrap();
+        approx::assert_relative_eq!(tweener.move_to(time), 2.0, epsilon = 0.0001);
+        assert_eq!(tweener.time_at_value(6.0), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use crate::{CubicIn, Looper, Oscillator};
+
+        let mut tweener = Tweener::new(0.0f32, 10.0, 4.0, Oscillator::new(CubicIn));
+        tweener.set_time_scale(0.5);
+        tweener.move_by(3.0);
+
+        let json = serde_json::to_string(&tweener).unwrap();
+        let mut loaded: Tweener<f32, f32, Oscillator<CubicIn>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, tweener);
+        assert_eq!(loaded.move_by(1.0), tweener.move_by(1.0));
+
+        // retargeted tweeners keep their carried velocity
+        let mut tweener = Tweener::new(0.0f32, 10.0, 4.0, Linear);
+        tweener.move_to(2.0);
+        tweener.retarget(-10.0, 2.0);
+        let mut loaded: Tweener<f32, f32, Linear> =
+            serde_json::from_str(&serde_json::to_string(&tweener).unwrap()).unwrap();
+        assert_eq!(loaded.move_to(0.5), tweener.move_to(0.5));
+
+        let fixed = FixedTweener::new(0, 4, 4, Looper::new(Linear), 1);
+        let loaded: FixedTweener<i32, i32, Looper<Linear>> =
+            serde_json::from_str(&serde_json::to_string(&fixed).unwrap()).unwrap();
+        assert!(loaded.take(6).eq(fixed.take(6)));
+
+        let state: CurrentTimeState = serde_json::from_str("\"Finished\"").unwrap();
+        assert_eq!(state, CurrentTimeState::Finished);
+    }
+
+    #[test]
+    fn set_values() {
+        let mut tweener = Tweener::new(0, 10, 10, Linear);
+        tweener.set_final_value(20);
+        assert_eq!(tweener.move_to(5), 10);
+        tweener.set_initial_value(10);
+        assert_eq!(tweener.move_to(5), 15);
+        assert_eq!(tweener.final_value(), 20);
+    }
+
+    #[test]
+    fn fixed_playback() {
+        let mut tweener = Tweener::new_at(0, 4, 4, Linear, 4);
+        tweener.set_direction(PlaybackDirection::Backward);
+        let values: std::vec::Vec<_> = tweener.into_fixed(1).collect();
+        assert_eq!(*values, [3, 2, 1, 0]);
+
+        let mut fixed = Tweener::new(0, 4, 4, Linear).into_fixed(1);
+        assert_eq!(fixed.next(), Some(1));
+        fixed.pause();
+        assert_eq!(fixed.next(), None);
+        assert_eq!(fixed.current_time, 1);
+        fixed.set_direction(PlaybackDirection::Backward);
+        fixed.resume();
+        assert_eq!(fixed.next(), Some(0));
+        assert_eq!(fixed.next(), None);
+
+        // a stopped tweener ends the iterator, rather than repeating itself forever
+        let mut fixed = Tweener::new(0, 4, 4, Linear).into_fixed(1);
+        fixed.set_time_scale(0.0);
+        assert_eq!(fixed.by_ref().count(), 0);
+        fixed.set_time_scale(1.0);
+        assert_eq!(fixed.count(), 4);
+    }
+
     #[test]
     fn shortcuts() {
         Tweener::back_in(0, 0, 0);
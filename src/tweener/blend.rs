@@ -0,0 +1,237 @@
+use crate::{Tween, TweenValue};
+
+/// A [Blend] is a wrapper around two [Tween]s which runs both and mixes their values together by a
+/// [BlendWeight], such as "70% [CubicOut](crate::CubicOut), 30% [BackOut](crate::BackOut)":
+///
+/// ```
+/// # use tween::{BackOut, Blend, CubicOut, Tween, Tweener};
+/// let mut tweener = Tweener::new(0.0f32, 100.0, 1.0, Blend::new(CubicOut, BackOut, 0.3));
+///
+/// let blended = tweener.move_to(0.5);
+/// let expected = 0.7 * CubicOut.tween(100.0f32, 0.5) + 0.3 * BackOut.tween(100.0f32, 0.5);
+/// assert!((blended - expected).abs() < 0.001);
+/// ```
+///
+/// The weight is how much of the second tween is used, so a weight of `0.0` is only the first
+/// tween, and `1.0` is only the second. A weight can also change over the course of the tween, such
+/// as with [crossfade](Self::crossfade), which starts out as the first tween and ends as the
+/// second.
+///
+/// A Blend is finite only if both of its tweens are, so it can blend a [Looper](crate::Looper) or
+/// an [Extrapolator](crate::Extrapolator) too, and can itself be wrapped in either.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blend<A, B> {
+    /// The first tween, used at a weight of `0.0`.
+    pub a: A,
+
+    /// The second tween, used at a weight of `1.0`.
+    pub b: B,
+
+    /// How much of `b` is used.
+    pub weight: BlendWeight,
+}
+
+impl<A, B> Blend<A, B> {
+    /// Creates a new Blend which always uses `weight` of `b`, and the rest of `a`.
+    pub fn new(a: A, b: B, weight: f32) -> Self {
+        Self::with_weight(a, b, BlendWeight::Constant(weight))
+    }
+
+    /// Creates a new Blend which crossfades from `a` to `b` over the course of the tween. See
+    /// [BlendWeight::Crossfade].
+    pub fn crossfade(a: A, b: B) -> Self {
+        Self::with_weight(a, b, BlendWeight::Crossfade)
+    }
+
+    /// Creates a new Blend with any [BlendWeight].
+    pub fn with_weight(a: A, b: B, weight: BlendWeight) -> Self {
+        Self { a, b, weight }
+    }
+}
+
+impl<Value, A, B> Tween<Value> for Blend<A, B>
+where
+    Value: TweenValue,
+    A: Tween<Value>,
+    B: Tween<Value>,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let weight = self.weight.at(percent);
+        let a = self.a.tween(value_delta, percent);
+        let b = self.b.tween(value_delta, percent);
+
+        a + (b - a).scale(weight)
+    }
+
+    #[inline]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        let weight = self.weight.at(percent);
+        let a_slope = self.a.derivative(value_delta, percent);
+        let b_slope = self.b.derivative(value_delta, percent);
+        let value = a_slope + (b_slope - a_slope).scale(weight);
+
+        // a weight which changes over time also moves the value between the two tweens
+        let weight_slope = self.weight.slope(percent);
+        if weight_slope == 0.0 {
+            return value;
+        }
+
+        let a = self.a.tween(value_delta, percent);
+        let b = self.b.tween(value_delta, percent);
+
+        value + (b - a).scale(weight_slope)
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.a.is_finite() && self.b.is_finite()
+    }
+}
+
+/// How much of the second tween a [Blend] uses at each percent.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendWeight {
+    /// Always uses the same weight.
+    Constant(f32),
+
+    /// Uses the percent as the weight, so the Blend starts as the first tween and ends as the
+    /// second. The weight is clamped to `0.0..=1.0`, so the Blend is only the first tween before
+    /// it starts and only the second after it ends.
+    Crossfade,
+
+    /// Gets the weight from a function of the percent, such as `|t| t * t` to crossfade later
+    /// on. Since this is a function pointer, it's skipped by `serde`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Curve(fn(f32) -> f32),
+}
+
+impl BlendWeight {
+    /// Returns the weight at the given percent.
+    #[inline]
+    pub fn at(self, percent: f32) -> f32 {
+        match self {
+            Self::Constant(weight) => weight,
+            Self::Crossfade => percent.clamp(0.0, 1.0),
+            Self::Curve(curve) => curve(percent),
+        }
+    }
+
+    /// Returns how fast the weight is changing at the given percent.
+    fn slope(self, percent: f32) -> f32 {
+        match self {
+            Self::Constant(_) => 0.0,
+            Self::Crossfade if (0.0..=1.0).contains(&percent) => 1.0,
+            Self::Crossfade => 0.0,
+            Self::Curve(curve) => {
+                const STEP: f32 = 1.0 / 1024.0;
+
+                (curve(percent + STEP) - curve(percent - STEP)) / (2.0 * STEP)
+            }
+        }
+    }
+}
+
+/// The default is a [Constant](BlendWeight::Constant) weight of `0.5`, which is half of each.
+impl Default for BlendWeight {
+    fn default() -> Self {
+        Self::Constant(0.5)
+    }
+}
+
+/// Two [Curve](BlendWeight::Curve)s are the same if they're the same function.
+impl PartialEq for BlendWeight {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Constant(a), Self::Constant(b)) => a == b,
+            (Self::Crossfade, Self::Crossfade) => true,
+            (Self::Curve(a), Self::Curve(b)) => core::ptr::fn_addr_eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BackOut, CubicIn, CubicOut, Extrapolator, Linear, Looper, Oscillator, QuadIn, Tweener};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn constant() {
+        let mut blend = Blend::new(CubicOut, BackOut, 0.3);
+        for i in 0..=10 {
+            let percent = i as f32 / 10.0;
+            let expected = CubicOut.tween(100.0f32, percent) * 0.7 + BackOut.tween(100.0f32, percent) * 0.3;
+            assert_relative_eq!(blend.tween(100.0f32, percent), expected, max_relative = 0.0001);
+        }
+
+        // the ends are still the ends
+        assert_eq!(blend.tween(100.0f32, 0.0), 0.0);
+        assert_relative_eq!(blend.tween(100.0f32, 1.0), 100.0);
+
+        assert_eq!(Blend::new(QuadIn, Linear, 0.0).tween(10.0f32, 0.5), 2.5);
+        assert_eq!(Blend::new(QuadIn, Linear, 1.0).tween(10.0f32, 0.5), 5.0);
+        assert_eq!(Blend::new(QuadIn, Linear, 0.5).tween(10, 1.0), 10);
+    }
+
+    #[test]
+    fn crossfade() {
+        let mut tweener = Tweener::new(0.0f32, 10.0, 4.0, Blend::crossfade(CubicIn, CubicOut));
+        assert_eq!(tweener.move_to(0.0), 0.0);
+        // at the middle, the blend is half of each
+        assert_relative_eq!(tweener.move_to(2.0), 5.0);
+        assert_eq!(tweener.move_to(4.0), 10.0);
+
+        let mut curved = Blend::with_weight(Linear, QuadIn, BlendWeight::Curve(|t| t * t));
+        assert_relative_eq!(curved.tween(1.0f32, 0.5), 0.5 * 0.75 + 0.25 * 0.25);
+        assert_eq!(BlendWeight::Crossfade.at(2.0), 1.0);
+        assert_eq!(BlendWeight::default(), BlendWeight::Constant(0.5));
+    }
+
+    #[test]
+    fn derivative() {
+        let mut blends = [
+            Blend::new(CubicOut, BackOut, 0.3),
+            Blend::crossfade(CubicOut, BackOut),
+            Blend::with_weight(CubicOut, BackOut, BlendWeight::Curve(|t| t * t)),
+        ];
+
+        for blend in &mut blends {
+            for i in 1..20 {
+                let percent = (i as f32 + 0.3) / 20.0;
+                let step = 1.0 / 1024.0;
+                let expected =
+                    (blend.tween(10.0f32, percent + step) - blend.tween(10.0f32, percent - step)) / (2.0 * step);
+
+                assert_relative_eq!(
+                    blend.derivative(10.0f32, percent),
+                    expected,
+                    max_relative = 0.01,
+                    epsilon = 0.01
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finite() {
+        assert!(Tween::<f32>::is_finite(&Blend::new(Linear, CubicIn, 0.5)));
+        assert!(!Tween::<f32>::is_finite(&Blend::new(Looper::new(Linear), CubicIn, 0.5)));
+        assert!(!Tween::<f32>::is_finite(&Blend::new(
+            Linear,
+            Extrapolator::new(CubicIn),
+            0.5
+        )));
+
+        // blends compose with the adapters in both directions
+        let mut looping = Tweener::new(0.0f32, 10.0, 1.0, Looper::new(Blend::new(Linear, QuadIn, 0.5)));
+        assert_relative_eq!(looping.move_to(1.5), 3.75);
+
+        let mut oscillating = Tweener::new(0.0f32, 10.0, 1.0, Blend::new(Oscillator::new(Linear), Linear, 0.5));
+        assert_relative_eq!(oscillating.move_to(1.5), 0.5 * 5.0 + 0.5 * 15.0);
+        assert!(!oscillating.is_finished());
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+
+/// A [Lens] writes a tweened value into part of a `Target`, such as a field of a struct.
+///
+/// Any closure which takes `(&mut Target, Value)` is a Lens, so most of the time you won't need
+/// to implement this yourself:
+///
+/// ```
+/// # use tween::Lens;
+/// struct Sprite {
+///     alpha: f32,
+/// }
+///
+/// let mut lens = |sprite: &mut Sprite, alpha| sprite.alpha = alpha;
+///
+/// let mut sprite = Sprite { alpha: 1.0 };
+/// lens.apply(&mut sprite, 0.5);
+/// assert_eq!(sprite.alpha, 0.5);
+/// ```
+///
+/// Lenses are used by [BoundTweener], which applies its value to a target every time it moves.
+pub trait Lens<Target: ?Sized, Value> {
+    /// Writes `value` into `target`.
+    fn apply(&mut self, target: &mut Target, value: Value);
+}
+
+impl<Target, Value, F> Lens<Target, Value> for F
+where
+    Target: ?Sized,
+    F: FnMut(&mut Target, Value),
+{
+    #[inline]
+    fn apply(&mut self, target: &mut Target, value: Value) {
+        self(target, value)
+    }
+}
+
+/// A BoundTweener is a [Tweener] wrapper which writes its value into a target with a [Lens]
+/// whenever it moves, rather than only returning it. Make one with [Tweener::bind].
+///
+/// ```
+/// # use tween::{Tweener};
+/// struct Transform {
+///     position: (f32, f32),
+///     scale: f32,
+/// }
+///
+/// let mut transform = Transform {
+///     position: (0.0, 0.0),
+///     scale: 1.0,
+/// };
+///
+/// let mut grow = Tweener::linear(1.0, 2.0, 1.0).bind(|t: &mut Transform, scale| t.scale = scale);
+/// let mut slide = Tweener::linear(0.0, 10.0, 2.0).bind(|t: &mut Transform, x| t.position.0 = x);
+///
+/// // in your main loop...
+/// grow.move_by(&mut transform, 0.5);
+/// slide.move_by(&mut transform, 0.5);
+///
+/// assert_eq!(transform.scale, 1.5);
+/// assert_eq!(transform.position, (2.5, 0.0));
+/// ```
+///
+/// The target isn't held by the BoundTweener, so it's given to every call instead. This lets one
+/// target be driven by many BoundTweeners, each writing a different part of it. To keep many
+/// of them in one list, give them all the same tween and lens types, such as an [Easing] and a
+/// boxed closure:
+///
+/// ```
+/// # use tween::{BoundTweener, Easing, Tweener};
+/// # struct Sprite {
+/// #     alpha: f32,
+/// #     rotation: f32,
+/// # }
+/// type SpriteAnimation = BoundTweener<f32, f32, Easing, Box<dyn FnMut(&mut Sprite, f32)>>;
+///
+/// let mut animations: Vec<SpriteAnimation> = vec![
+///     Tweener::new(1.0, 0.0, 1.0, Easing::QuadIn).bind(Box::new(|s, alpha| s.alpha = alpha)),
+///     Tweener::new(0.0, 90.0, 2.0, Easing::SineInOut).bind(Box::new(|s, r| s.rotation = r)),
+/// ];
+///
+/// let mut sprite = Sprite {
+///     alpha: 1.0,
+///     rotation: 0.0,
+/// };
+/// for _ in 0..2 {
+///     for animation in &mut animations {
+///         animation.move_by(&mut sprite, 0.5);
+///     }
+///     animations.retain(|animation| !animation.is_finished());
+/// }
+///
+/// assert_eq!(sprite.alpha, 0.0);
+/// assert_eq!(sprite.rotation, 45.0);
+/// assert_eq!(animations.len(), 1);
+/// ```
+///
+/// [Easing]: crate::Easing
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundTweener<Value, Time, T, L> {
+    /// The Lens which the value is written with.
+    pub lens: L,
+
+    /// The internal tweener that we've bound a Lens to.
+    pub tweener: Tweener<Value, Time, T>,
+}
+
+impl<Value, Time, T, L> BoundTweener<Value, Time, T, L>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Creates a new [BoundTweener] out of a [Tweener] and the [Lens] it writes with.
+    pub fn new(tweener: Tweener<Value, Time, T>, lens: L) -> Self {
+        Self { lens, tweener }
+    }
+
+    /// Moves the tweener to a given Time, like [Tweener::move_to], and writes the value into
+    /// `target`. The value is returned as well.
+    #[inline]
+    pub fn move_to<Target>(&mut self, target: &mut Target, position: Time) -> Value
+    where
+        Target: ?Sized,
+        L: Lens<Target, Value>,
+    {
+        let value = self.tweener.move_to(position);
+        self.lens.apply(target, value);
+
+        value
+    }
+
+    /// Drives the tweener forward, like [Tweener::move_by], and writes the value into `target`.
+    /// The value is returned as well.
+    #[inline]
+    pub fn move_by<Target>(&mut self, target: &mut Target, delta: Time) -> Value
+    where
+        Target: ?Sized,
+        L: Lens<Target, Value>,
+    {
+        let value = self.tweener.move_by(delta);
+        self.lens.apply(target, value);
+
+        value
+    }
+
+    /// Takes the [Tweener] back out, dropping the [Lens].
+    pub fn unbind(self) -> Tweener<Value, Time, T> {
+        self.tweener
+    }
+}
+
+impl<Value, Time, T, L> core::ops::Deref for BoundTweener<Value, Time, T, L> {
+    type Target = Tweener<Value, Time, T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.tweener
+    }
+}
+
+impl<Value, Time, T, L> core::ops::DerefMut for BoundTweener<Value, Time, T, L> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.tweener
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Color {
+        rgb: [u8; 3],
+        a: f32,
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Sprite {
+        position: (i32, i32),
+        color: Color,
+    }
+
+    fn set_x(sprite: &mut Sprite, x: i32) {
+        sprite.position.0 = x;
+    }
+
+    #[test]
+    fn bound() {
+        let mut sprite = Sprite::default();
+
+        let mut fade = Tweener::new(0.0f32, 1.0, 4.0, Linear).bind(|s: &mut Sprite, a| s.color.a = a);
+        assert_eq!(fade.move_by(&mut sprite, 1.0), 0.25);
+        assert_eq!(sprite.color.a, 0.25);
+        assert_eq!(fade.move_to(&mut sprite, 8.0), 1.0);
+        assert_eq!(sprite.color.a, 1.0);
+        assert!(fade.is_finished());
+
+        // functions work too, and the tweener's settings still apply
+        let mut slide = Tweener::linear(0, 100, 10).bind(set_x);
+        slide.set_time_scale(2.0);
+        slide.move_by(&mut sprite, 1);
+        assert_eq!(sprite.position, (20, 0));
+        assert_eq!(sprite.color, Color { rgb: [0; 3], a: 1.0 });
+
+        let tweener = slide.unbind();
+        assert_eq!(tweener.current_time, 2);
+    }
+
+    #[test]
+    fn custom_lens() {
+        struct Channel(usize);
+
+        impl Lens<Color, u8> for Channel {
+            fn apply(&mut self, target: &mut Color, value: u8) {
+                target.rgb[self.0] = value;
+            }
+        }
+
+        let mut color = Color::default();
+        let mut green = BoundTweener::new(Tweener::linear(0u8, 200, 4), Channel(1));
+        green.move_by(&mut color, 1);
+        green.move_by(&mut color, 1);
+        assert_eq!(color.rgb, [0, 100, 0]);
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{PlaybackDirection, Tween, TweenTime, TweenValue, Tweener, math};
+
+/// Something which happened while a [Tweener] moved, reported by
+/// [move_by_with_events](Tweener::move_by_with_events) and
+/// [move_to_with_events](Tweener::move_to_with_events).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TweenEvent {
+    /// What happened.
+    pub kind: TweenEventKind,
+
+    /// Which way the time was moving when it happened.
+    pub direction: PlaybackDirection,
+}
+
+/// The kinds of [TweenEvent].
+///
+/// Each kind is tied to a point in time, and is reported whenever the time moves across that
+/// point, in either direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TweenEventKind {
+    /// The time moved off of zero going forwards, or reached zero going backwards.
+    Start,
+
+    /// The time reached the duration going forwards, or moved back below it going backwards.
+    /// This is only reported for finite tweens.
+    End,
+
+    /// The time reached `n * duration` going forwards, or moved back below it going backwards,
+    /// where `n` is the value given here, starting from `1`. This is only reported for tweens
+    /// which aren't finite, like a [Looper](crate::Looper), and is reported in place of
+    /// [End](Self::End).
+    Loop(u32),
+
+    /// The time reached the marker at this index going forwards, or moved back below it going
+    /// backwards.
+    Marker(usize),
+}
+
+/// An [Iterator] over the [TweenEvent]s from one move of a [Tweener], in the order they happened.
+///
+/// This is lazy and doesn't allocate, so if you don't need the events you can simply drop it.
+#[derive(Debug, Clone)]
+pub struct TweenEvents<'a, Time> {
+    from: f32,
+    to: f32,
+    duration: f32,
+    finite: bool,
+    markers: &'a [Time],
+    next_loop: Option<u32>,
+    last: Option<(f32, u8, usize)>,
+}
+
+impl<'a, Time: TweenTime> TweenEvents<'a, Time> {
+    fn new(from: f32, to: f32, duration: f32, finite: bool, markers: &'a [Time]) -> Self {
+        let mut events = Self {
+            from,
+            to,
+            duration,
+            finite,
+            markers,
+            next_loop: None,
+            last: None,
+        };
+
+        if !finite && duration > 0.0 {
+            events.next_loop = match events.direction() {
+                Some(PlaybackDirection::Forward) => {
+                    let mut n = math::floor(from / duration).max(0.0) as u32;
+                    while n as f32 * duration <= from {
+                        n += 1;
+                    }
+
+                    Some(n)
+                }
+                Some(PlaybackDirection::Backward) => {
+                    let mut n = math::ceil(from / duration).max(0.0) as u32;
+                    while n > 0 && n as f32 * duration > from {
+                        n -= 1;
+                    }
+
+                    (n > 0).then_some(n)
+                }
+                None => None,
+            };
+        }
+
+        events
+    }
+
+    fn direction(&self) -> Option<PlaybackDirection> {
+        if self.to > self.from {
+            Some(PlaybackDirection::Forward)
+        } else if self.to < self.from {
+            Some(PlaybackDirection::Backward)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the time moved across `point`, where `point` belongs to the times after
+    /// it (or before it, if `inclusive_below`).
+    fn crosses(&self, point: f32, inclusive_below: bool) -> bool {
+        let (low, high) = if self.from < self.to {
+            (self.from, self.to)
+        } else {
+            (self.to, self.from)
+        };
+
+        if inclusive_below {
+            low <= point && point < high
+        } else {
+            low < point && point <= high
+        }
+    }
+}
+
+impl<Time: TweenTime> Iterator for TweenEvents<'_, Time> {
+    type Item = TweenEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let direction = self.direction()?;
+
+        // events are ordered by how far along the move they are, and then by which happens first
+        // at the same time. going forwards, the start happens just *after* zero, and everything
+        // else happens right at its time, and going backwards, the reverse.
+        let (sign, ranks) = match direction {
+            PlaybackDirection::Forward => (1.0, [2, 1, 0]),
+            PlaybackDirection::Backward => (-1.0, [0, 1, 2]),
+        };
+        let [start_rank, end_rank, marker_rank] = ranks;
+
+        let last = self.last;
+        let mut best: Option<((f32, u8, usize), TweenEventKind)> = None;
+        let mut consider = |key: (f32, u8, usize), kind: TweenEventKind| {
+            if last.is_none_or(|last| key > last) && best.is_none_or(|(best, _)| key < best) {
+                best = Some((key, kind));
+            }
+        };
+
+        if self.crosses(0.0, true) {
+            consider((0.0, start_rank, 0), TweenEventKind::Start);
+        }
+
+        if self.finite {
+            if self.crosses(self.duration, false) {
+                consider((sign * self.duration, end_rank, 0), TweenEventKind::End);
+            }
+        } else if let Some(n) = self.next_loop {
+            let time = n as f32 * self.duration;
+            if self.crosses(time, false) {
+                consider((sign * time, end_rank, 0), TweenEventKind::Loop(n));
+            }
+        }
+
+        for (index, marker) in self.markers.iter().enumerate() {
+            let time = marker.to_f32();
+            if self.crosses(time, false) {
+                consider((sign * time, marker_rank, index), TweenEventKind::Marker(index));
+            }
+        }
+
+        let (key, kind) = best?;
+        self.last = Some(key);
+
+        if let TweenEventKind::Loop(n) = kind {
+            self.next_loop = match direction {
+                PlaybackDirection::Forward => n.checked_add(1),
+                PlaybackDirection::Backward => n.checked_sub(1).filter(|n| *n > 0),
+            };
+        }
+
+        Some(TweenEvent { kind, direction })
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, T>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Works just like [move_by](Self::move_by), but also returns the [TweenEvent]s which happened
+    /// along the way, such as reaching the end or passing one of the given `markers`.
+    ///
+    /// ```
+    /// # use tween::{Linear, PlaybackDirection, TweenEvent, TweenEventKind, Tweener};
+    /// let markers = [2, 5];
+    /// let mut tweener = Tweener::new(0, 10, 10, Linear);
+    ///
+    /// let (value, events) = tweener.move_by_with_events(6, &markers);
+    /// assert_eq!(value, 6);
+    ///
+    /// let kinds = events.map(|event| event.kind);
+    /// assert!(kinds.eq([
+    ///     TweenEventKind::Start,
+    ///     TweenEventKind::Marker(0),
+    ///     TweenEventKind::Marker(1)
+    /// ]));
+    ///
+    /// let (_, mut events) = tweener.move_by_with_events(-2, &markers);
+    /// assert_eq!(
+    ///     events.next(),
+    ///     Some(TweenEvent {
+    ///         kind: TweenEventKind::Marker(1),
+    ///         direction: PlaybackDirection::Backward
+    ///     })
+    /// );
+    /// assert_eq!(events.next(), None);
+    /// ```
+    ///
+    /// A single large move reports every event it passes, in order. Markers can be in any order,
+    /// and are reported by their index in `markers`.
+    pub fn move_by_with_events<'a>(&mut self, delta: Time, markers: &'a [Time]) -> (Value, TweenEvents<'a, Time>) {
+        let from = self.current_time;
+        let value = self.move_by(delta);
+
+        (value, self.events_since(from, markers))
+    }
+
+    /// Works just like [move_to](Self::move_to), but also returns the [TweenEvent]s between the
+    /// old time and the new one. See [move_by_with_events](Self::move_by_with_events).
+    pub fn move_to_with_events<'a>(&mut self, position: Time, markers: &'a [Time]) -> (Value, TweenEvents<'a, Time>) {
+        let from = self.current_time;
+        let value = self.move_to(position);
+
+        (value, self.events_since(from, markers))
+    }
+
+    fn events_since<'a>(&self, from: Time, markers: &'a [Time]) -> TweenEvents<'a, Time> {
+        TweenEvents::new(
+            from.to_f32(),
+            self.current_time.to_f32(),
+            self.duration.to_f32(),
+            self.tween.is_finite(),
+            markers,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Looper};
+
+    use PlaybackDirection::Backward;
+    use TweenEventKind::{End, Loop, Marker, Start};
+
+    fn kinds<Time: TweenTime>(events: TweenEvents<'_, Time>) -> impl Iterator<Item = TweenEventKind> + '_ {
+        events.map(|event| event.kind)
+    }
+
+    #[test]
+    fn forward() {
+        let markers = [8, 3, 3, 10];
+        let mut tweener = Tweener::new_at(0, 10, 10, Linear, -2);
+
+        let (_, events) = tweener.move_by_with_events(1, &markers);
+        assert_eq!(events.count(), 0);
+
+        // one big move sees everything, in order
+        let (value, events) = tweener.move_by_with_events(20, &markers);
+        assert_eq!(value, 10);
+        assert!(kinds(events).eq([Start, Marker(1), Marker(2), Marker(0), Marker(3), End]));
+
+        let (_, events) = tweener.move_by_with_events(1, &markers);
+        assert_eq!(events.count(), 0);
+    }
+
+    #[test]
+    fn exact_landings() {
+        let markers = [5];
+        let mut tweener = Tweener::new(0, 10, 10, Linear);
+
+        // landing on a marker reports it once, and not again when leaving it
+        let (_, events) = tweener.move_by_with_events(5, &markers);
+        assert!(kinds(events).eq([Start, Marker(0)]));
+        let (_, events) = tweener.move_by_with_events(5, &markers);
+        assert!(kinds(events).eq([End]));
+
+        // and it's the same going backwards
+        let (_, events) = tweener.move_to_with_events(5, &markers);
+        assert!(kinds(events).eq([End]));
+        let (_, mut events) = tweener.move_to_with_events(0, &markers);
+        let marker = TweenEvent {
+            kind: Marker(0),
+            direction: Backward,
+        };
+        assert_eq!(events.next(), Some(marker));
+        assert_eq!(events.next().map(|event| event.kind), Some(Start));
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn backward_playback() {
+        let markers = [2.5];
+        let mut tweener = Tweener::new_at(0.0, 10.0, 10.0, Linear, 10.0);
+        tweener.set_direction(PlaybackDirection::Backward);
+
+        let (value, events) = tweener.move_by_with_events(20.0, &markers);
+        assert_eq!(value, 0.0);
+        assert!(events.map(|event| (event.kind, event.direction)).eq([
+            (End, Backward),
+            (Marker(0), Backward),
+            (Start, Backward)
+        ]));
+    }
+
+    #[test]
+    fn loops() {
+        let markers = [1];
+        let mut tweener = Tweener::new(0, 4, 4, Looper::new(Linear));
+
+        let (_, events) = tweener.move_by_with_events(9, &markers);
+        assert!(kinds(events).eq([Start, Marker(0), Loop(1), Loop(2)]));
+
+        let (_, events) = tweener.move_by_with_events(3, &markers);
+        assert!(kinds(events).eq([Loop(3)]));
+
+        let (_, events) = tweener.move_to_with_events(3, &markers);
+        assert!(events.map(|event| (event.kind, event.direction)).eq([
+            (Loop(3), Backward),
+            (Loop(2), Backward),
+            (Loop(1), Backward),
+        ]));
+
+        let (_, events) = tweener.move_to_with_events(0, &markers);
+        assert!(kinds(events).eq([Marker(0), Start]));
+        let (_, events) = tweener.move_to_with_events(0, &markers);
+        assert_eq!(events.count(), 0);
+    }
+}
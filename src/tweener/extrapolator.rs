@@ -13,6 +13,7 @@ use crate::{Tween, TweenValue};
 /// [Linear]: crate::Linear
 /// [SineIn]: crate::SineIn
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Extrapolator<T: ?Sized>(pub T);
 
@@ -34,6 +35,11 @@ where
         self.0.tween(value_delta, percent)
     }
 
+    #[inline(always)]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.0.derivative(value_delta, percent)
+    }
+
     fn is_finite(&self) -> bool {
         false
     }
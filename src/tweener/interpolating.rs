@@ -0,0 +1,221 @@
+use crate::{CurrentTimeState, Interpolate, Tween, TweenTime};
+
+/// An InterpolatingTweener is a [Tweener](crate::Tweener) for values which implement
+/// [Interpolate] rather than [TweenValue](crate::TweenValue), such as quaternions.
+///
+/// Rather than scaling a delta between the start and end values, the InterpolatingTweener runs its
+/// [Tween] on `1.0` to find how far along it is, and then interpolates between the start and
+/// end values by that amount. This means every easing in this library works with it:
+///
+/// ```
+/// # use tween::{InterpolatingTweener, SineInOut, Tween};
+/// let mut tweener = InterpolatingTweener::new(0.0f32, 10.0, 10, SineInOut);
+///
+/// assert_eq!(tweener.move_to(3), 10.0 * SineInOut.tween(1.0, 0.3));
+/// assert_eq!(tweener.move_to(20), 10.0);
+/// ```
+///
+/// With the `glam` or `nalgebra` features, quaternions are interpolated with slerp:
+///
+/// ```
+/// # #[cfg(feature = "glam")]
+/// # {
+/// # use tween::{InterpolatingTweener, Linear};
+/// use glam::Quat;
+///
+/// let end = Quat::from_rotation_z(core::f32::consts::FRAC_PI_2);
+/// let mut tweener = InterpolatingTweener::new(Quat::IDENTITY, end, 1.0, Linear);
+///
+/// let halfway = tweener.move_to(0.5);
+/// assert!(halfway.abs_diff_eq(Quat::from_rotation_z(core::f32::consts::FRAC_PI_4), 0.0001));
+/// # }
+/// ```
+///
+/// An InterpolatingTweener clamps its output in the same way as a [Tweener](crate::Tweener).
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord, Copy)]
+pub struct InterpolatingTweener<Value, Time, T: ?Sized> {
+    /// The current time of the InterpolatingTweener. You can change this value at will without
+    /// running the Tween, or change it with `move_by`.
+    pub current_time: Time,
+
+    /// The InterpolatingTweener's total duration.
+    pub duration: Time,
+
+    values: (Value, Value),
+
+    /// The actual underlying Tween.
+    pub tween: T,
+}
+
+impl<Value, Time, T> InterpolatingTweener<Value, Time, T>
+where
+    Time: TweenTime,
+    Value: Interpolate,
+    T: Tween<f32>,
+{
+    /// Creates a new [InterpolatingTweener] out of a [Tween], start and end [Interpolate] values,
+    /// and [TweenTime] duration.
+    pub fn new(start: Value, end: Value, duration: Time, tween: T) -> Self {
+        Self::new_at(start, end, duration, tween, Time::ZERO)
+    }
+
+    /// Creates a new [InterpolatingTweener] out of a [Tween], start and end [Interpolate] values,
+    /// [TweenTime] duration, and [TweenTime] current time.
+    pub fn new_at(start: Value, end: Value, duration: Time, tween: T, current_time: Time) -> Self {
+        Self {
+            current_time,
+            duration,
+            values: (start, end),
+            tween,
+        }
+    }
+
+    /// Maps an `InterpolatingTweener<Value, Time, T>` to an `InterpolatingTweener<Value, Time, R>`.
+    /// This can be useful for boxing inner tweens.
+    pub fn map<R: Tween<f32>>(self, mut f: impl FnMut(T) -> R) -> InterpolatingTweener<Value, Time, R> {
+        InterpolatingTweener {
+            current_time: self.current_time,
+            duration: self.duration,
+            values: self.values,
+            tween: f(self.tween),
+        }
+    }
+
+    /// Moves the tween to a given Time, returning the value there. See
+    /// [Tweener::move_to](crate::Tweener::move_to) for more information.
+    #[inline]
+    pub fn move_to(&mut self, position: Time) -> Value {
+        self.current_time = position;
+
+        let pct = position.to_f32() / self.duration.to_f32();
+        if self.tween.is_finite() {
+            if pct < 0.0 {
+                return self.values.0;
+            } else if pct > 1.0 {
+                return self.values.1;
+            }
+        }
+
+        Value::interpolate(self.values.0, self.values.1, self.tween.tween(1.0, pct))
+    }
+
+    /// Drives the [InterpolatingTweener] forward X steps in time.
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> Value {
+        self.current_time += delta;
+
+        self.move_to(self.current_time)
+    }
+
+    /// The initial value a tween was set to start at.
+    #[inline]
+    pub fn initial_value(&self) -> Value {
+        self.values.0
+    }
+
+    /// The final value the tween should end at.
+    #[inline]
+    pub fn final_value(&self) -> Value {
+        self.values.1
+    }
+
+    /// Returns `true` is the InterpolatingTweener's [current_time] is greater than or equal to
+    /// `0`.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_started(&self) -> bool {
+        self.current_time_state() != CurrentTimeState::Waiting
+    }
+
+    /// Returns `true` is the InterpolatingTweener's [current_time] is greater than or equal to
+    /// `duration`.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_finished(&self) -> bool {
+        self.current_time_state() == CurrentTimeState::Finished
+    }
+
+    /// Returns `true` is the InterpolatingTweener's [current_time] is greater than or equal to `0`
+    /// but less than `duration`.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_valid(&self) -> bool {
+        self.current_time_state() == CurrentTimeState::Valid
+    }
+
+    /// Returns `CurrentTimeState` based on the InterpolatingTweener's [current_time].
+    ///
+    /// [current_time]: Self::current_time
+    pub fn current_time_state(&self) -> CurrentTimeState {
+        if !self.tween.is_finite() {
+            return CurrentTimeState::Valid;
+        }
+
+        let pct = self.current_time.to_f32() / self.duration.to_f32();
+        if pct < 0.0 {
+            CurrentTimeState::Waiting
+        } else if pct >= 1.0 {
+            CurrentTimeState::Finished
+        } else {
+            CurrentTimeState::Valid
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BackOut, Linear, Looper};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn interpolating_tweener() {
+        let mut tweener = InterpolatingTweener::new(0.0f64, 10.0, 4.0, Linear);
+
+        assert_eq!(tweener.move_by(1.0), 2.5);
+        assert!(tweener.is_valid());
+        assert_eq!(tweener.move_by(10.0), 10.0);
+        assert!(tweener.is_finished());
+        assert_eq!(tweener.move_to(-1.0), 0.0);
+        assert!(!tweener.is_started());
+
+        // overshooting easings pass their overshoot along
+        let mut tweener = InterpolatingTweener::new(0.0f32, 10.0, 10, BackOut);
+        assert!(tweener.move_to(8) > 10.0);
+
+        let mut tweener = InterpolatingTweener::new(0.0f32, 10.0, 10, Looper::new(Linear));
+        assert_relative_eq!(tweener.move_to(15), 5.0);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_quat() {
+        use core::f32::consts::FRAC_PI_3;
+        use glam::Quat;
+
+        let start = Quat::from_rotation_y(0.0);
+        let end = Quat::from_rotation_y(FRAC_PI_3 * 2.0);
+        let mut tweener = InterpolatingTweener::new(start, end, 2, Linear);
+
+        // a component-wise lerp wouldn't stay normalized
+        let halfway = tweener.move_to(1);
+        assert_relative_eq!(halfway.length(), 1.0, epsilon = 0.0001);
+        assert!(halfway.abs_diff_eq(Quat::from_rotation_y(FRAC_PI_3), 0.0001));
+        assert_eq!(tweener.move_to(2), end);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra_rotations() {
+        use core::f32::consts::FRAC_PI_3;
+        use nalgebra::{UnitComplex, UnitQuaternion, Vector3};
+
+        let end = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), FRAC_PI_3 * 2.0);
+        let mut tweener = InterpolatingTweener::new(UnitQuaternion::identity(), end, 2, Linear);
+        assert_relative_eq!(tweener.move_to(1).angle(), FRAC_PI_3, epsilon = 0.0001);
+
+        let end = UnitComplex::new(FRAC_PI_3 * 2.0);
+        let mut tweener = InterpolatingTweener::new(UnitComplex::new(0.0), end, 2, Linear);
+        assert_relative_eq!(tweener.move_to(1).angle(), FRAC_PI_3, epsilon = 0.0001);
+    }
+}
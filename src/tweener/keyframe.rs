@@ -0,0 +1,496 @@
+use crate::{CurrentTimeState, Tween, TweenTime, TweenValue};
+use alloc::{boxed::Box, vec::Vec};
+
+/// A [KeyframeTrack] is a list of [Keyframe]s, each with its own time, value, and
+/// [Interpolation] to the next key, which evaluates like a [Tweener](crate::Tweener) over the
+/// whole track.
+///
+/// Use a `KeyframeTrack` when an animation has more than two stops, like a color gradient over
+/// a particle's life.
+///
+/// ```
+/// # use tween::{Interpolation, KeyframeTrack, CubicInOut};
+/// let mut track = KeyframeTrack::new(0.0, 0.0, Interpolation::Linear);
+/// track.insert(1.0, 10.0, Interpolation::Tween(Box::new(CubicInOut)));
+/// track.insert(2.0, 0.0, Interpolation::Step);
+/// track.insert(3.0, 5.0, Interpolation::Linear);
+///
+/// assert_eq!(track.move_to(0.5), 5.0);
+/// assert_eq!(track.move_to(1.5), 5.0);
+/// // a step holds its value until the next key
+/// assert_eq!(track.move_to(2.5), 0.0);
+/// assert_eq!(track.move_to(3.0), 5.0);
+/// assert!(track.is_finished());
+/// ```
+///
+/// ## Lookup
+///
+/// Finding the current key is a binary search, but the track also remembers the last key it
+/// found, so moving through a track in order (the common case) is constant time.
+///
+/// ## Clamping
+///
+/// A `KeyframeTrack` returns its first value before its first key's time, and its last value at
+/// or after its last key's time.
+#[derive(Debug)]
+pub struct KeyframeTrack<Value, Time> {
+    /// The current time of the track. You can change this value at will without running the
+    /// track, or change it with `move_by`.
+    pub current_time: Time,
+
+    keys: Vec<Keyframe<Value, Time>>,
+    tangents: Vec<Value>,
+    cursor: usize,
+}
+
+impl<Value, Time> KeyframeTrack<Value, Time>
+where
+    Value: KeyframeValue,
+    Time: TweenTime,
+{
+    /// Creates a new [KeyframeTrack] with a single key. A track always has at least one key.
+    pub fn new(time: Time, value: Value, interpolation: Interpolation<Value>) -> Self {
+        let mut track = Self {
+            current_time: Time::ZERO,
+            keys: Vec::new(),
+            tangents: Vec::new(),
+            cursor: 0,
+        };
+        track.insert(time, value, interpolation);
+
+        track
+    }
+
+    /// Inserts a key into the track, returning its index. `interpolation` describes how the
+    /// track moves from this key to the next one.
+    ///
+    /// If a key already exists at `time`, it is replaced.
+    pub fn insert(&mut self, time: Time, value: Value, interpolation: Interpolation<Value>) -> usize {
+        let index = self.keys.partition_point(|key| key.time < time);
+        let keyframe = Keyframe {
+            time,
+            value,
+            interpolation,
+        };
+
+        match self.keys.get_mut(index) {
+            Some(key) if key.time == time => *key = keyframe,
+            _ => self.keys.insert(index, keyframe),
+        }
+
+        self.recalculate_tangents();
+
+        index
+    }
+
+    /// Removes the key at the given index and returns it.
+    ///
+    /// Returns `None` if there is no key at that index, or if it is the last key in the track.
+    pub fn remove(&mut self, index: usize) -> Option<Keyframe<Value, Time>> {
+        if index >= self.keys.len() || self.keys.len() == 1 {
+            return None;
+        }
+
+        let output = self.keys.remove(index);
+        self.recalculate_tangents();
+        self.cursor = 0;
+
+        Some(output)
+    }
+
+    /// The keys in this track, sorted by time.
+    pub fn keys(&self) -> &[Keyframe<Value, Time>] {
+        &self.keys
+    }
+
+    /// The number of keys in this track. This is always at least `1`.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Moves the track to a given Time, returning the value there.
+    ///
+    /// Times before the first key return the first key's value, and times at or after the last
+    /// key return the last key's value.
+    pub fn move_to(&mut self, position: Time) -> Value {
+        self.current_time = position;
+
+        let last = self.keys.len() - 1;
+        if position < self.keys[0].time {
+            return self.keys[0].value;
+        }
+        if position >= self.keys[last].time {
+            return self.keys[last].value;
+        }
+
+        let index = self.find_segment(position);
+        self.cursor = index;
+
+        let (start, end) = (&self.keys[index], &self.keys[index + 1]);
+        let (start_time, start_value) = (start.time, start.value);
+        let (end_time, end_value) = (end.time, end.value);
+        let value_delta = end_value - start_value;
+
+        let duration = (end_time - start_time).to_f32();
+        let percent = (position - start_time).to_f32() / duration;
+
+        match &mut self.keys[index].interpolation {
+            Interpolation::Step => start_value,
+            Interpolation::Linear => value_delta.scale(percent) + start_value,
+            Interpolation::Tween(tween) => tween.tween(value_delta, percent) + start_value,
+            Interpolation::MonotoneCubic => {
+                // cubic hermite basis, with the tangents scaled to this segment
+                let p2 = percent * percent;
+                let p3 = p2 * percent;
+
+                let h01 = 3.0 * p2 - 2.0 * p3;
+                let h10 = p3 - 2.0 * p2 + percent;
+                let h11 = p3 - p2;
+
+                start_value
+                    + value_delta.scale(h01)
+                    + self.tangents[index].scale(h10 * duration)
+                    + self.tangents[index + 1].scale(h11 * duration)
+            }
+        }
+    }
+
+    /// Drives the track forward X steps in time.
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> Value {
+        self.current_time += delta;
+
+        self.move_to(self.current_time)
+    }
+
+    /// The value of the first key.
+    #[inline]
+    pub fn initial_value(&self) -> Value {
+        self.keys[0].value
+    }
+
+    /// The value of the last key.
+    #[inline]
+    pub fn final_value(&self) -> Value {
+        self.keys[self.keys.len() - 1].value
+    }
+
+    /// The time between the first and last keys.
+    pub fn duration(&self) -> Time {
+        self.keys[self.keys.len() - 1].time - self.keys[0].time
+    }
+
+    /// Returns `true` is the track's [current_time] is greater than or equal to its first key's
+    /// time.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_started(&self) -> bool {
+        self.current_time_state() != CurrentTimeState::Waiting
+    }
+
+    /// Returns `true` is the track's [current_time] is greater than or equal to its last key's
+    /// time.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_finished(&self) -> bool {
+        self.current_time_state() == CurrentTimeState::Finished
+    }
+
+    /// Returns `true` is the track's [current_time] is between its first key's time and its
+    /// last key's time.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_valid(&self) -> bool {
+        self.current_time_state() == CurrentTimeState::Valid
+    }
+
+    /// Returns `CurrentTimeState` based on the track's [current_time].
+    ///
+    /// [current_time]: Self::current_time
+    pub fn current_time_state(&self) -> CurrentTimeState {
+        if self.current_time < self.keys[0].time {
+            CurrentTimeState::Waiting
+        } else if self.current_time >= self.keys[self.keys.len() - 1].time {
+            CurrentTimeState::Finished
+        } else {
+            CurrentTimeState::Valid
+        }
+    }
+
+    /// Finds the index of the key which starts the segment containing `position`. The caller
+    /// must ensure `position` is within the track.
+    fn find_segment(&self, position: Time) -> usize {
+        // check where we were last time, and the segment after it, before searching
+        for index in [self.cursor, self.cursor + 1] {
+            if let (Some(start), Some(end)) = (self.keys.get(index), self.keys.get(index + 1))
+                && start.time <= position
+                && position < end.time
+            {
+                return index;
+            }
+        }
+
+        self.keys.partition_point(|key| key.time <= position) - 1
+    }
+
+    fn recalculate_tangents(&mut self) {
+        self.tangents.clear();
+
+        let slope = |start: &Keyframe<Value, Time>, end: &Keyframe<Value, Time>| {
+            let duration = (end.time - start.time).to_f32();
+            ((end.value - start.value).scale(1.0 / duration), duration)
+        };
+
+        let len = self.keys.len();
+        if len == 1 {
+            let value = self.keys[0].value;
+            self.tangents.push(value.scale(0.0));
+            return;
+        }
+
+        for index in 0..len {
+            // the ends of the track only have one neighbor, so we pretend it's on both sides
+            let previous = slope(&self.keys[index.max(1) - 1], &self.keys[index.max(1)]);
+            let next = slope(&self.keys[index.min(len - 2)], &self.keys[index.min(len - 2) + 1]);
+
+            self.tangents
+                .push(Value::monotone_tangent(previous.0, previous.1, next.0, next.1));
+        }
+    }
+}
+
+/// A single key within a [KeyframeTrack].
+#[derive(Debug)]
+pub struct Keyframe<Value, Time> {
+    /// The time of this key.
+    pub time: Time,
+
+    /// The value of the track at this key.
+    pub value: Value,
+
+    /// How the track moves from this key to the next one. This is unused on the last key.
+    pub interpolation: Interpolation<Value>,
+}
+
+/// How a [KeyframeTrack] moves from one key to the next.
+pub enum Interpolation<Value> {
+    /// Holds the key's value until the next key.
+    Step,
+
+    /// Moves in a straight line to the next key.
+    Linear,
+
+    /// Moves to the next key with the given [Tween], such as [CubicInOut](crate::CubicInOut).
+    Tween(Box<dyn Tween<Value>>),
+
+    /// Moves to the next key with a monotone cubic spline, which passes smoothly through each key
+    /// but never overshoots between them. See [KeyframeValue] for more information.
+    MonotoneCubic,
+}
+
+impl<Value> core::fmt::Debug for Interpolation<Value> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Step => write!(f, "Step"),
+            Self::Linear => write!(f, "Linear"),
+            Self::Tween(_) => write!(f, "Tween(..)"),
+            Self::MonotoneCubic => write!(f, "MonotoneCubic"),
+        }
+    }
+}
+
+/// A `KeyframeValue` is a [TweenValue] which can be placed within a [KeyframeTrack].
+///
+/// The only method here is used by [Interpolation::MonotoneCubic]. The default implementation
+/// returns a flat tangent, which never overshoots but will ease in and out of every key.
+/// Floating point types in this library (and the math libraries behind feature flags) override it
+/// to give a properly smooth spline. If you have your own `TweenValue`, you can simply write:
+///
+/// ```
+/// # use tween::{TweenValue, KeyframeValue};
+/// # #[derive(Debug, Clone, Copy)]
+/// # struct MyValue(f32);
+/// # impl core::ops::Add for MyValue { type Output = Self; fn add(self, o: Self) -> Self { Self(self.0 + o.0) } }
+/// # impl core::ops::Sub for MyValue { type Output = Self; fn sub(self, o: Self) -> Self { Self(self.0 - o.0) } }
+/// # impl TweenValue for MyValue { fn scale(self, s: f32) -> Self { Self(self.0 * s) } }
+/// impl KeyframeValue for MyValue {}
+/// ```
+pub trait KeyframeValue: TweenValue {
+    /// Returns the tangent (in value per unit of time) at a key, given the slopes (also in value
+    /// per unit of time) and durations of the segments on either side of it.
+    ///
+    /// To keep a spline monotone, this should be calculated component-wise, and be `0` for any
+    /// component where the two slopes have different signs.
+    fn monotone_tangent(previous_slope: Self, previous_duration: f32, next_slope: Self, next_duration: f32) -> Self {
+        let _ = (previous_duration, next_slope, next_duration);
+        previous_slope.scale(0.0)
+    }
+}
+
+/// Calculates a monotone tangent with the Fritsch-Butland formula, which (unlike a plain average
+/// of the slopes) can never overshoot.
+#[inline]
+pub(crate) fn monotone_tangent(
+    previous_slope: f64,
+    previous_duration: f64,
+    next_slope: f64,
+    next_duration: f64,
+) -> f64 {
+    if previous_slope * next_slope <= 0.0 {
+        return 0.0;
+    }
+
+    let previous_weight = 2.0 * next_duration + previous_duration;
+    let next_weight = next_duration + 2.0 * previous_duration;
+
+    (previous_weight + next_weight) / (previous_weight / previous_slope + next_weight / next_slope)
+}
+
+impl KeyframeValue for f32 {
+    fn monotone_tangent(previous_slope: Self, previous_duration: f32, next_slope: Self, next_duration: f32) -> Self {
+        monotone_tangent(
+            previous_slope as f64,
+            previous_duration as f64,
+            next_slope as f64,
+            next_duration as f64,
+        ) as f32
+    }
+}
+
+impl KeyframeValue for f64 {
+    fn monotone_tangent(previous_slope: Self, previous_duration: f32, next_slope: Self, next_duration: f32) -> Self {
+        monotone_tangent(
+            previous_slope,
+            previous_duration as f64,
+            next_slope,
+            next_duration as f64,
+        )
+    }
+}
+
+// integers can't represent most tangents anyway, so they use flat ones
+impl KeyframeValue for u8 {}
+impl KeyframeValue for i8 {}
+impl KeyframeValue for i16 {}
+impl KeyframeValue for u16 {}
+impl KeyframeValue for i32 {}
+impl KeyframeValue for i64 {}
+impl KeyframeValue for u32 {}
+impl KeyframeValue for u64 {}
+impl KeyframeValue for i128 {}
+impl KeyframeValue for u128 {}
+impl KeyframeValue for usize {}
+impl KeyframeValue for isize {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CubicInOut;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn step_and_linear() {
+        let mut track = KeyframeTrack::new(0, 0, Interpolation::Step);
+        track.insert(10, 10, Interpolation::Linear);
+        track.insert(20, 30, Interpolation::Linear);
+
+        assert_eq!(track.move_to(-5), 0);
+        assert!(!track.is_started());
+        assert_eq!(track.move_to(5), 0);
+        assert_eq!(track.move_to(10), 10);
+        assert_eq!(track.move_to(15), 20);
+        assert_eq!(track.move_to(20), 30);
+        assert!(track.is_finished());
+        assert_eq!(track.duration(), 20);
+    }
+
+    #[test]
+    fn tween_segments() {
+        let mut track = KeyframeTrack::new(0.0, 0.0, Interpolation::Tween(Box::new(CubicInOut)));
+        track.insert(10.0, 100.0, Interpolation::Linear);
+
+        for time in 0..=10 {
+            let time = time as f32;
+            assert_relative_eq!(track.move_to(time), CubicInOut.tween(100.0, time / 10.0));
+        }
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut track = KeyframeTrack::new(10, 1, Interpolation::Linear);
+        assert_eq!(track.insert(0, 0, Interpolation::Linear), 0);
+        assert_eq!(track.insert(20, 2, Interpolation::Linear), 2);
+        assert_eq!(track.insert(15, 5, Interpolation::Linear), 2);
+        // replaces the existing key
+        assert_eq!(track.insert(15, 7, Interpolation::Step), 2);
+
+        assert_eq!(track.len(), 4);
+        assert_eq!(track.keys()[2].value, 7);
+        assert_eq!(track.move_to(17), 7);
+
+        assert_eq!(track.remove(2).map(|v| v.value), Some(7));
+        assert_eq!(track.move_to(15), 1);
+
+        assert!(track.remove(10).is_none());
+        track.remove(0);
+        track.remove(0);
+        assert_eq!(track.len(), 1);
+        assert!(track.remove(0).is_none());
+        assert_eq!(track.move_to(100), 2);
+    }
+
+    #[test]
+    fn cursor() {
+        let mut track = KeyframeTrack::new(0, 0, Interpolation::Linear);
+        for i in 1..=10 {
+            track.insert(i * 10, i * 100, Interpolation::Linear);
+        }
+
+        // forwards, backwards, and jumping should all agree
+        let forward: Vec<_> = (0..=100).map(|t| track.move_to(t)).collect();
+        let backward: Vec<_> = (0..=100).rev().map(|t| track.move_to(t)).collect();
+        assert!(forward.iter().eq(backward.iter().rev()));
+        assert_eq!(forward, (0..=100).map(|t| t * 10).collect::<Vec<_>>());
+
+        assert_eq!(track.move_to(95), 950);
+        assert_eq!(track.move_to(5), 50);
+        assert_eq!(track.move_by(20), 250);
+    }
+
+    #[test]
+    fn monotone_cubic() {
+        let mut track = KeyframeTrack::new(0.0, 0.0, Interpolation::MonotoneCubic);
+        track.insert(1.0, 1.0, Interpolation::MonotoneCubic);
+        track.insert(2.0, 1.1, Interpolation::MonotoneCubic);
+        track.insert(4.0, 0.0, Interpolation::MonotoneCubic);
+
+        assert_eq!(track.move_to(0.0), 0.0);
+        assert_relative_eq!(track.move_to(1.0), 1.0);
+        assert_relative_eq!(track.move_to(2.0), 1.1);
+
+        let mut last = 0.0;
+        for i in 1..=200 {
+            let time = i as f32 / 100.0;
+            let value = track.move_to(time);
+
+            // rising up to the peak, and never overshooting it
+            assert!(value >= last, "{} < {} at {}", value, last, time);
+            assert!(value <= 1.1 + f32::EPSILON);
+            last = value;
+        }
+
+        for i in 201..=400 {
+            let value = track.move_to(i as f32 / 100.0);
+            assert!(value <= last);
+            assert!(value >= 0.0);
+            last = value;
+        }
+
+        // smooth through the key: the slope on either side of it is about the same
+        let h = 0.001;
+        let left = (track.move_to(1.0) - track.move_to(1.0 - h)) / h;
+        let right = (track.move_to(1.0 + h) - track.move_to(1.0)) / h;
+        assert_relative_eq!(left, right, epsilon = 0.01);
+    }
+}
@@ -3,6 +3,7 @@ use crate::{Tween, TweenValue};
 /// A [Looper] is a wrapper around a [Tween], which makes it so that
 /// every time the tweener *would* fuse (end), it loops from the start.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Looper<T: ?Sized>(pub T);
 
@@ -33,6 +34,18 @@ where
         self.0.tween(value_delta, percent)
     }
 
+    #[inline(always)]
+    fn derivative(&mut self, value_delta: Value, mut percent: f32) -> Value {
+        if percent != 0.0 {
+            percent %= 1.0;
+            if percent == 0.0 {
+                percent = 1.0
+            }
+        }
+
+        self.0.derivative(value_delta, percent)
+    }
+
     fn is_finite(&self) -> bool {
         false
     }
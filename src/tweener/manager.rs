@@ -0,0 +1,372 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+use alloc::{boxed::Box, vec::Vec};
+
+/// A [Tweener] with its tween boxed, as stored in a [TweenManager].
+pub type BoxedTweener<Value, Time> = Tweener<Value, Time, Box<dyn Tween<Value>>>;
+
+/// A [TweenManager] owns many [Tweener]s at once, updating them all with a single `delta` and
+/// removing them once they finish.
+///
+/// Each Tweener added with [insert](Self::insert) is given a [TweenHandle], which stays valid until
+/// the Tweener finishes or is [cancelled](Self::cancel). Once it's gone, the handle won't find
+/// anything, even if its slot is reused by a newer Tweener.
+///
+/// ```
+/// # use tween::{TweenManager, Tweener};
+/// let mut manager = TweenManager::new();
+///
+/// let fade = manager.insert(Tweener::linear(0.0, 1.0, 1.0));
+/// let bounce = manager.insert(Tweener::bounce_out(10.0, 20.0, 2.0));
+///
+/// // in your main loop...
+/// let finished = manager.update(1.0);
+/// assert_eq!(finished, [(fade, 1.0)]);
+///
+/// assert!(!manager.contains(fade));
+/// assert_eq!(manager.value(bounce), Some(17.65625));
+/// ```
+///
+/// The Tweeners can use any tween, since each is boxed as a `Box<dyn Tween<Value>>`, but they all
+/// share the same `Value` and `Time`. Tweeners which never finish, such as those using a [Looper]
+/// or [Oscillator], stay until they're cancelled.
+///
+/// [Looper]: crate::Looper
+/// [Oscillator]: crate::Oscillator
+pub struct TweenManager<Value, Time> {
+    slots: Vec<Slot<Value, Time>>,
+    free: Vec<u32>,
+    len: usize,
+    finished: Vec<(TweenHandle, Value)>,
+}
+
+struct Slot<Value, Time> {
+    generation: u32,
+    entry: Option<Entry<Value, Time>>,
+}
+
+struct Entry<Value, Time> {
+    tweener: BoxedTweener<Value, Time>,
+    value: Value,
+}
+
+impl<Value, Time> TweenManager<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    /// Creates a new, empty TweenManager.
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+            finished: Vec::new(),
+        }
+    }
+
+    /// Adds a Tweener to the manager, returning its handle.
+    ///
+    /// The Tweener isn't moved until the next [update](Self::update), so its [value](Self::value)
+    /// starts as the value at its `current_time`.
+    pub fn insert<T>(&mut self, tweener: Tweener<Value, Time, T>) -> TweenHandle
+    where
+        T: Tween<Value> + 'static,
+    {
+        let mut tweener = tweener.map(|tween| Box::new(tween) as Box<dyn Tween<Value>>);
+        let value = tweener.move_to(tweener.current_time);
+        let entry = Some(Entry { tweener, value });
+        self.len += 1;
+
+        match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.entry = entry;
+
+                TweenHandle {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                let index = u32::try_from(self.slots.len()).expect("too many tweeners in a TweenManager");
+                self.slots.push(Slot { generation: 0, entry });
+
+                TweenHandle { index, generation: 0 }
+            }
+        }
+    }
+
+    /// Moves every Tweener by `delta`, and removes the ones which have finished.
+    ///
+    /// Returns the handle and final value of each Tweener removed, in the order of their slots.
+    /// This is also available from [finished](Self::finished) until the next update.
+    pub fn update(&mut self, delta: Time) -> &[(TweenHandle, Value)] {
+        self.finished.clear();
+
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            let Some(entry) = &mut slot.entry else {
+                continue;
+            };
+
+            entry.value = entry.tweener.move_by(delta);
+
+            if entry.tweener.is_finished() {
+                let handle = TweenHandle {
+                    index: index as u32,
+                    generation: slot.generation,
+                };
+                self.finished.push((handle, entry.value));
+
+                slot.entry = None;
+                slot.generation = slot.generation.wrapping_add(1);
+                self.free.push(index as u32);
+                self.len -= 1;
+            }
+        }
+
+        &self.finished
+    }
+
+    /// Returns the handle and final value of each Tweener which finished during the last
+    /// [update](Self::update).
+    pub fn finished(&self) -> &[(TweenHandle, Value)] {
+        &self.finished
+    }
+
+    /// Removes a Tweener before it finishes, returning it. Returns `None` if the handle's Tweener
+    /// is already gone.
+    ///
+    /// A cancelled Tweener isn't reported by [finished](Self::finished).
+    pub fn cancel(&mut self, handle: TweenHandle) -> Option<BoxedTweener<Value, Time>> {
+        let slot = self.slots.get_mut(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+
+        let entry = slot.entry.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(handle.index);
+        self.len -= 1;
+
+        Some(entry.tweener)
+    }
+
+    /// Removes every Tweener. Every handle given out so far will no longer find anything.
+    pub fn clear(&mut self) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if slot.entry.take().is_some() {
+                slot.generation = slot.generation.wrapping_add(1);
+                self.free.push(index as u32);
+            }
+        }
+
+        self.len = 0;
+        self.finished.clear();
+    }
+}
+
+impl<Value, Time> TweenManager<Value, Time> {
+    /// Returns `true` if the handle's Tweener is still in the manager.
+    pub fn contains(&self, handle: TweenHandle) -> bool {
+        self.entry(handle).is_some()
+    }
+
+    /// Returns the handle's Tweener, if it's still in the manager.
+    pub fn get(&self, handle: TweenHandle) -> Option<&BoxedTweener<Value, Time>> {
+        self.entry(handle).map(|entry| &entry.tweener)
+    }
+
+    /// Returns the handle's Tweener mutably, if it's still in the manager. This can be used to
+    /// pause it, retarget it, and so on.
+    ///
+    /// Changes to the Tweener are picked up by the next [update](Self::update), including
+    /// whether it has finished.
+    pub fn get_mut(&mut self, handle: TweenHandle) -> Option<&mut BoxedTweener<Value, Time>> {
+        let slot = self.slots.get_mut(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+
+        slot.entry.as_mut().map(|entry| &mut entry.tweener)
+    }
+
+    /// Returns the value of the handle's Tweener as of the last [update](Self::update), if it's
+    /// still in the manager.
+    pub fn value(&self, handle: TweenHandle) -> Option<Value>
+    where
+        Value: Copy,
+    {
+        self.entry(handle).map(|entry| entry.value)
+    }
+
+    /// Returns an iterator over the handle and value of every Tweener, as of the last
+    /// [update](Self::update).
+    pub fn values(&self) -> impl Iterator<Item = (TweenHandle, Value)> + '_
+    where
+        Value: Copy,
+    {
+        self.entries().map(|(handle, entry)| (handle, entry.value))
+    }
+
+    /// Returns an iterator over the handle and Tweener of every Tweener.
+    pub fn iter(&self) -> impl Iterator<Item = (TweenHandle, &BoxedTweener<Value, Time>)> {
+        self.entries().map(|(handle, entry)| (handle, &entry.tweener))
+    }
+
+    /// Returns an iterator over the handle and Tweener of every Tweener, mutably.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (TweenHandle, &mut BoxedTweener<Value, Time>)> {
+        self.slots.iter_mut().enumerate().filter_map(|(index, slot)| {
+            let handle = TweenHandle {
+                index: index as u32,
+                generation: slot.generation,
+            };
+
+            slot.entry.as_mut().map(|entry| (handle, &mut entry.tweener))
+        })
+    }
+
+    /// The number of Tweeners in the manager.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the manager has no Tweeners.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn entry(&self, handle: TweenHandle) -> Option<&Entry<Value, Time>> {
+        let slot = self.slots.get(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+
+        slot.entry.as_ref()
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (TweenHandle, &Entry<Value, Time>)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let handle = TweenHandle {
+                index: index as u32,
+                generation: slot.generation,
+            };
+
+            slot.entry.as_ref().map(|entry| (handle, entry))
+        })
+    }
+}
+
+impl<Value, Time> Default for TweenManager<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Value, Time> core::fmt::Debug for TweenManager<Value, Time>
+where
+    Value: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.entries().map(|(handle, entry)| (handle, &entry.value)))
+            .finish()
+    }
+}
+
+/// A handle to a Tweener within a [TweenManager], returned by [TweenManager::insert].
+///
+/// Handles are cheap to copy and compare. A handle whose Tweener has finished or been cancelled
+/// never finds another Tweener, even one placed in the same slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TweenHandle {
+    index: u32,
+    generation: u32,
+}
+
+impl TweenHandle {
+    /// The index of the Tweener's slot within its TweenManager. Slots are reused once their
+    /// Tweener is removed.
+    pub fn index(self) -> usize {
+        self.index as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Looper, Oscillator, SineIn};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn update() {
+        let mut manager = TweenManager::new();
+        let short = manager.insert(Tweener::linear(0, 10, 2));
+        let long = manager.insert(Tweener::linear(0, 100, 4));
+        let looping = manager.insert(Tweener::new(0, 10, 2, Looper::new(crate::Linear)));
+        assert_eq!(manager.len(), 3);
+        assert_eq!(manager.value(long), Some(0));
+
+        assert!(manager.update(1).is_empty());
+        assert_eq!(
+            manager.values().collect::<Vec<_>>(),
+            [(short, 5), (long, 25), (looping, 5)]
+        );
+
+        assert_eq!(manager.update(1), [(short, 10)]);
+        assert_eq!(manager.finished(), [(short, 10)]);
+        assert!(!manager.contains(short));
+        assert_eq!(manager.get(short).map(|t| t.duration), None);
+        assert_eq!(manager.len(), 2);
+
+        assert_eq!(manager.update(2), [(long, 100)]);
+        assert!(manager.update(100).is_empty());
+        assert!(manager.finished().is_empty());
+
+        // infinite tweens stay until they're cancelled
+        assert_eq!(manager.value(looping), Some(10));
+        assert!(manager.cancel(looping).is_some());
+        assert!(manager.cancel(looping).is_none());
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn handles() {
+        let mut manager = TweenManager::new();
+        let first = manager.insert(Tweener::sine_in(0.0f32, 1.0, 1.0));
+        let second = manager.insert(Tweener::new(0.0, 1.0, 1.0, SineIn));
+        assert_ne!(first, second);
+
+        // a reused slot gets a new handle
+        manager.cancel(first);
+        let third = manager.insert(Tweener::new(0.0, 2.0, 1.0, Oscillator::new(SineIn)));
+        assert_eq!(third.index(), first.index());
+        assert_ne!(third, first);
+        assert!(manager.get(first).is_none());
+        assert!(manager.get_mut(first).is_none());
+        assert_eq!(manager.get(third).unwrap().final_value(), 2.0);
+
+        // changes through handles are picked up by the next update
+        manager.get_mut(second).unwrap().pause();
+        manager.update(0.5);
+        assert_eq!(manager.value(second), Some(0.0));
+        assert_eq!(
+            manager.iter().map(|(handle, _)| handle).collect::<Vec<_>>(),
+            [third, second]
+        );
+
+        for (_, tweener) in manager.iter_mut() {
+            tweener.current_time = 1.0;
+        }
+        assert_eq!(manager.update(0.0), [(second, 1.0)]);
+
+        manager.clear();
+        assert!(manager.is_empty());
+        assert!(!manager.contains(third));
+        assert_eq!(manager.insert(Tweener::linear(0.0, 1.0, 1.0)).index(), 0);
+    }
+}
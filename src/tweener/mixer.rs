@@ -0,0 +1,423 @@
+use crate::{BoxedTweener, Linear, Tween, TweenTime, TweenValue, Tweener};
+use alloc::{boxed::Box, vec::Vec};
+
+/// A [Mixer] combines many [Tweener]s, called layers, into one value, such as an idle bob, a
+/// hit shake and a hover scale all playing on one property at once.
+///
+/// Each layer has a [BlendMode] and a weight, which is usually `0.0` to `1.0`. Every
+/// [update](Self::update) moves each layer and blends it in, in the order the layers were added,
+/// starting from the Mixer's `base` value:
+///
+/// - an [Override](BlendMode::Override) layer moves the value towards its own by its weight, so at
+///   `1.0` it replaces everything below it.
+/// - an [Additive](BlendMode::Additive) layer adds its own value, scaled by its weight, on top.
+///
+/// ```
+/// # use tween::{BlendMode, Mixer, Tweener, Oscillator, Linear};
+/// let mut mixer = Mixer::new(0.0);
+///
+/// // walk from 0 to 10 over 10 seconds...
+/// let walk = mixer.add_layer(Tweener::linear(0.0, 10.0, 10.0), BlendMode::Override);
+/// // ...while bobbing up and down by 1 every 2 seconds
+/// let bob = mixer.add_layer(
+///     Tweener::new(0.0, 1.0, 1.0, Oscillator::new(Linear)),
+///     BlendMode::Additive,
+/// );
+///
+/// assert_eq!(mixer.update(1.0), 1.0 + 1.0);
+///
+/// // fade the bob out over the next 2 seconds, removing it once it's gone
+/// mixer.fade_out(bob, 2.0);
+/// // the bob is back down at 0 here, at half weight
+/// assert_eq!(mixer.update(1.0), 2.0);
+/// assert_eq!(mixer.update(1.0), 3.0);
+/// assert!(!mixer.contains(bob));
+/// assert!(mixer.contains(walk));
+/// ```
+///
+/// Layers which finish hold their final value, and stay until they're removed with
+/// [remove_layer](Self::remove_layer) or faded out with [fade_out](Self::fade_out).
+///
+/// A Mixer works with any [TweenValue], blending with its `scale`, `+` and `-`. Integers round
+/// their scaled values down, so they blend in steps.
+pub struct Mixer<Value, Time> {
+    /// The value blended onto when there are no layers.
+    pub base: Value,
+
+    layers: Vec<Layer<Value, Time>>,
+    next_id: u32,
+    value: Value,
+}
+
+struct Layer<Value, Time> {
+    id: LayerId,
+    mode: BlendMode,
+    weight: f32,
+    fade: Option<Fade<Time>>,
+    tweener: BoxedTweener<Value, Time>,
+    value: Value,
+}
+
+struct Fade<Time> {
+    tweener: Tweener<f32, Time, Linear>,
+    remove: bool,
+}
+
+impl<Value, Time> Mixer<Value, Time>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+{
+    /// Creates a new Mixer with no layers, which blends them onto `base`.
+    pub fn new(base: Value) -> Self {
+        Self {
+            base,
+            layers: Vec::new(),
+            next_id: 0,
+            value: base,
+        }
+    }
+
+    /// Adds a layer on top of the others, with a weight of `1.0`, returning its id.
+    pub fn add_layer<T>(&mut self, tweener: Tweener<Value, Time, T>, mode: BlendMode) -> LayerId
+    where
+        T: Tween<Value> + 'static,
+    {
+        self.add_layer_with_weight(tweener, mode, 1.0)
+    }
+
+    /// Adds a layer on top of the others with the given weight, returning its id. Use a weight of
+    /// `0.0` and then [fade_in](Self::fade_in) to bring a layer in smoothly.
+    pub fn add_layer_with_weight<T>(
+        &mut self,
+        tweener: Tweener<Value, Time, T>,
+        mode: BlendMode,
+        weight: f32,
+    ) -> LayerId
+    where
+        T: Tween<Value> + 'static,
+    {
+        let id = LayerId(self.next_id);
+        self.next_id += 1;
+
+        let mut tweener = tweener.map(|tween| Box::new(tween) as Box<dyn Tween<Value>>);
+        let value = tweener.move_to(tweener.current_time);
+
+        self.layers.push(Layer {
+            id,
+            mode,
+            weight,
+            fade: None,
+            tweener,
+            value,
+        });
+
+        id
+    }
+
+    /// Removes a layer, returning its Tweener. Returns `None` if the layer is already gone.
+    ///
+    /// The Mixer's [value](Self::value) doesn't change until the next [update](Self::update).
+    pub fn remove_layer(&mut self, id: LayerId) -> Option<BoxedTweener<Value, Time>> {
+        let index = self.layers.iter().position(|layer| layer.id == id)?;
+
+        Some(self.layers.remove(index).tweener)
+    }
+
+    /// Sets a layer's weight at once, stopping any fade it was in. Does nothing if the layer is
+    /// gone.
+    pub fn set_weight(&mut self, id: LayerId, weight: f32) {
+        if let Some(layer) = self.layer_entry_mut(id) {
+            layer.weight = weight;
+            layer.fade = None;
+        }
+    }
+
+    /// Fades a layer's weight from where it is now to `weight` over `duration`. Does nothing if
+    /// the layer is gone.
+    pub fn fade_to(&mut self, id: LayerId, weight: f32, duration: Time) {
+        self.fade(id, weight, duration, false);
+    }
+
+    /// Fades a layer's weight up to `1.0` over `duration`. This is [fade_to](Self::fade_to) with a
+    /// weight of `1.0`.
+    pub fn fade_in(&mut self, id: LayerId, duration: Time) {
+        self.fade(id, 1.0, duration, false);
+    }
+
+    /// Fades a layer's weight down to `0.0` over `duration`, and then removes it.
+    pub fn fade_out(&mut self, id: LayerId, duration: Time) {
+        self.fade(id, 0.0, duration, true);
+    }
+
+    fn fade(&mut self, id: LayerId, weight: f32, duration: Time, remove: bool) {
+        let Some(layer) = self.layer_entry_mut(id) else {
+            return;
+        };
+
+        if duration <= Time::ZERO {
+            layer.weight = weight;
+            layer.fade = None;
+
+            if remove {
+                self.remove_layer(id);
+            }
+        } else {
+            layer.fade = Some(Fade {
+                tweener: Tweener::new(layer.weight, weight, duration, Linear),
+                remove,
+            });
+        }
+    }
+
+    /// Moves every layer, and their fades, by `delta`, and returns the blended value.
+    ///
+    /// Layers which finish fading out are removed.
+    pub fn update(&mut self, delta: Time) -> Value {
+        let mut value = self.base;
+
+        self.layers.retain_mut(|layer| {
+            layer.value = layer.tweener.move_by(delta);
+
+            if let Some(fade) = &mut layer.fade {
+                layer.weight = fade.tweener.move_by(delta);
+
+                if fade.tweener.is_finished() {
+                    let remove = fade.remove;
+                    layer.fade = None;
+
+                    if remove {
+                        return false;
+                    }
+                }
+            }
+
+            value = layer.mode.blend(value, layer.value, layer.weight);
+            true
+        });
+
+        self.value = value;
+        value
+    }
+
+    /// Returns the blended value, as of the last [update](Self::update).
+    pub fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl<Value, Time> Mixer<Value, Time> {
+    /// Returns `true` if the layer is still in the Mixer.
+    pub fn contains(&self, id: LayerId) -> bool {
+        self.layers.iter().any(|layer| layer.id == id)
+    }
+
+    /// Returns a layer's Tweener, if it's still in the Mixer.
+    pub fn layer(&self, id: LayerId) -> Option<&BoxedTweener<Value, Time>> {
+        self.layers
+            .iter()
+            .find(|layer| layer.id == id)
+            .map(|layer| &layer.tweener)
+    }
+
+    /// Returns a layer's Tweener mutably, if it's still in the Mixer. This can be used to pause
+    /// it, retarget it, and so on.
+    pub fn layer_mut(&mut self, id: LayerId) -> Option<&mut BoxedTweener<Value, Time>> {
+        self.layer_entry_mut(id).map(|layer| &mut layer.tweener)
+    }
+
+    /// Returns a layer's weight, if it's still in the Mixer. While fading, this is the weight as
+    /// of the last [update](Self::update).
+    pub fn weight(&self, id: LayerId) -> Option<f32> {
+        self.layers
+            .iter()
+            .find(|layer| layer.id == id)
+            .map(|layer| layer.weight)
+    }
+
+    /// Returns a layer's [BlendMode], if it's still in the Mixer.
+    pub fn blend_mode(&self, id: LayerId) -> Option<BlendMode> {
+        self.layers.iter().find(|layer| layer.id == id).map(|layer| layer.mode)
+    }
+
+    /// Sets a layer's [BlendMode]. Does nothing if the layer is gone.
+    pub fn set_blend_mode(&mut self, id: LayerId, mode: BlendMode) {
+        if let Some(layer) = self.layer_entry_mut(id) {
+            layer.mode = mode;
+        }
+    }
+
+    /// Returns `true` if the layer is fading its weight.
+    pub fn is_fading(&self, id: LayerId) -> bool {
+        self.layers.iter().any(|layer| layer.id == id && layer.fade.is_some())
+    }
+
+    /// Returns an iterator over the ids of every layer, from the bottom to the top.
+    pub fn layers(&self) -> impl Iterator<Item = LayerId> + '_ {
+        self.layers.iter().map(|layer| layer.id)
+    }
+
+    /// The number of layers in the Mixer.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns `true` if the Mixer has no layers.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Removes every layer.
+    pub fn clear(&mut self) {
+        self.layers.clear();
+    }
+
+    fn layer_entry_mut(&mut self, id: LayerId) -> Option<&mut Layer<Value, Time>> {
+        self.layers.iter_mut().find(|layer| layer.id == id)
+    }
+}
+
+impl<Value, Time> core::fmt::Debug for Mixer<Value, Time>
+where
+    Value: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Mixer")
+            .field("base", &self.base)
+            .field("value", &self.value)
+            .field("layers", &self.layers.len())
+            .finish()
+    }
+}
+
+/// How a layer of a [Mixer] is blended with the layers below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendMode {
+    /// Moves the value towards this layer's value by its weight, so at a weight of `1.0` this
+    /// layer replaces every layer below it. This is the default.
+    #[default]
+    Override,
+
+    /// Adds this layer's value, scaled by its weight, onto the value. Additive layers usually
+    /// tween around zero, like a shake or a bob.
+    Additive,
+}
+
+impl BlendMode {
+    /// Blends `layer` onto `value` with the given weight.
+    #[inline]
+    pub fn blend<Value: TweenValue>(self, value: Value, layer: Value, weight: f32) -> Value {
+        match self {
+            // this is written without subtracting, so that unsigned values can override downwards
+            Self::Override => value.scale(1.0 - weight) + layer.scale(weight),
+            Self::Additive => value + layer.scale(weight),
+        }
+    }
+}
+
+/// An id for a layer within a [Mixer], returned by [Mixer::add_layer].
+///
+/// Ids are never reused within a Mixer, so an id whose layer was removed won't find another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LayerId(u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Looper, Oscillator};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn blending() {
+        assert_eq!(BlendMode::Override.blend(10.0, 20.0, 0.25), 12.5);
+        assert_eq!(BlendMode::Additive.blend(10.0, 20.0, 0.25), 15.0);
+        assert_eq!(BlendMode::Override.blend(10, 20, 1.0), 20);
+        assert_eq!(BlendMode::default(), BlendMode::Override);
+
+        let mut mixer = Mixer::new(100.0f32);
+        assert_eq!(mixer.update(1.0), 100.0);
+
+        let idle = mixer.add_layer_with_weight(Tweener::linear(0.0, 10.0, 10.0), BlendMode::Override, 0.5);
+        let shake = mixer.add_layer(
+            Tweener::new(-1.0, 1.0, 1.0, Oscillator::new(Linear)),
+            BlendMode::Additive,
+        );
+        let hover = mixer.add_layer_with_weight(
+            Tweener::new(1.0, 2.0, 2.0, Looper::new(Linear)),
+            BlendMode::Additive,
+            0.0,
+        );
+        assert_eq!(mixer.layers().collect::<Vec<_>>(), [idle, shake, hover]);
+
+        // halfway from 100 to the idle's 0.5, and the shake is at 0
+        assert_eq!(mixer.update(0.5), 50.25);
+        assert_eq!(mixer.value(), 50.25);
+
+        mixer.set_weight(hover, 1.0);
+        mixer.set_blend_mode(idle, BlendMode::Additive);
+        assert_eq!(mixer.blend_mode(idle), Some(BlendMode::Additive));
+        assert_eq!(mixer.update(0.5), 100.0 + 0.5 * 1.0 + 1.0 + 1.5);
+    }
+
+    #[test]
+    fn fades() {
+        let mut mixer = Mixer::new(0);
+        let base = mixer.add_layer(Tweener::linear(100, 100, 1), BlendMode::Override);
+        let layer = mixer.add_layer_with_weight(Tweener::linear(200, 200, 1), BlendMode::Override, 0.0);
+
+        mixer.fade_in(layer, 4);
+        assert!(mixer.is_fading(layer));
+        assert_eq!(mixer.update(1), 125);
+        assert_eq!(mixer.weight(layer), Some(0.25));
+        assert_eq!(mixer.update(3), 200);
+        assert!(!mixer.is_fading(layer));
+
+        // fades start from the current weight
+        mixer.fade_to(layer, 0.5, 2);
+        assert_eq!(mixer.update(1), 175);
+
+        // changing the weight directly stops a fade
+        mixer.set_weight(layer, 0.0);
+        assert!(!mixer.is_fading(layer));
+        assert_eq!(mixer.update(1), 100);
+
+        mixer.fade_in(layer, 0);
+        assert_eq!(mixer.weight(layer), Some(1.0));
+
+        mixer.fade_out(layer, 2);
+        assert_eq!(mixer.update(1), 150);
+        assert!(mixer.contains(layer));
+        assert_eq!(mixer.update(1), 100);
+        assert!(!mixer.contains(layer));
+        assert_eq!(mixer.weight(layer), None);
+
+        mixer.fade_out(base, 0);
+        assert!(mixer.is_empty());
+        assert_eq!(mixer.value(), 100);
+        assert_eq!(mixer.update(1), 0);
+
+        // removed ids aren't reused
+        let new = mixer.add_layer(Tweener::linear(0, 1, 1), BlendMode::Additive);
+        assert_ne!(new, base);
+        assert_ne!(new, layer);
+        assert!(mixer.layer_mut(new).is_some());
+        assert!(mixer.remove_layer(new).is_some());
+        assert!(mixer.remove_layer(new).is_none());
+    }
+
+    #[test]
+    fn unsigned() {
+        assert_eq!(BlendMode::Override.blend(100u8, 20, 0.5), 60);
+
+        // a layer below the value pulls it down, rather than underflowing
+        let mut mixer = Mixer::new(100u8);
+        let layer = mixer.add_layer(Tweener::linear(0u8, 50, 4), BlendMode::Override);
+        assert_eq!(mixer.update(0), 0);
+        assert_eq!(mixer.update(4), 50);
+
+        mixer.set_weight(layer, 0.5);
+        assert_eq!(mixer.update(0), 75);
+    }
+}
@@ -7,6 +7,7 @@ use crate::{Tween, TweenValue};
 /// beginning, it restarts it at the end and travels backwards. For many Tweens in this library,
 /// this is the same
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct Oscillator<T: ?Sized>(pub T);
 
@@ -24,19 +25,18 @@ where
 {
     #[inline(always)]
     fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
-        let temp = percent % 2.0;
+        let (percent, _) = fold(percent);
 
-        #[cfg(feature = "std")]
-        let (which_tween, percent) = { (temp.trunc(), percent.fract()) };
-
-        #[cfg(feature = "libm")]
-        let (which_tween, percent) = { (libm::truncf(temp), percent - libm::truncf(percent)) };
+        self.0.tween(value_delta, percent)
+    }
 
-        // note: we don't have to worry about 0/1 difference here, since the tween
-        // will get us to the same place
-        let percent = if which_tween == 0.0 { percent } else { 1.0 - percent };
+    #[inline(always)]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        let (percent, returning) = fold(percent);
+        let derivative = self.0.derivative(value_delta, percent);
 
-        self.0.tween(value_delta, percent)
+        // on the way back, time runs backwards through the tween
+        if returning { derivative.scale(-1.0) } else { derivative }
     }
 
     fn is_finite(&self) -> bool {
@@ -44,6 +44,28 @@ where
     }
 }
 
+/// Folds a percent into a ping pong, where `0..1` travels forwards and `1..2` travels back again.
+///
+/// Returns the folded percent, between `0` and `1`, and whether it's on the return leg.
+#[inline(always)]
+pub(super) fn fold(percent: f32) -> (f32, bool) {
+    let temp = percent % 2.0;
+
+    #[cfg(feature = "std")]
+    let (which_tween, percent) = { (temp.trunc(), percent.fract()) };
+
+    #[cfg(feature = "libm")]
+    let (which_tween, percent) = { (libm::truncf(temp), percent - libm::truncf(percent)) };
+
+    // note: we don't have to worry about 0/1 difference here, since the tween
+    // will get us to the same place
+    if which_tween == 0.0 {
+        (percent, false)
+    } else {
+        (1.0 - percent, true)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +150,13 @@ mod tests {
     fn type_test() {
         let _one_type: Oscillator<Linear>;
     }
+
+    #[test]
+    fn derivative() {
+        let mut oscillator = Oscillator::new(Linear);
+
+        assert_eq!(oscillator.derivative(2.0f32, 0.5), 2.0);
+        assert_eq!(oscillator.derivative(2.0f32, 1.5), -2.0);
+        assert_eq!(oscillator.derivative(2.0f32, 2.5), 2.0);
+    }
 }
@@ -0,0 +1,223 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener, math};
+
+/// A [Repeat] is a wrapper around a [Tween] which plays it a fixed number of times, with an
+/// optional gap between each iteration.
+///
+/// Unlike a [Looper](crate::Looper), a Repeat is finite as long as its tween is, so a [Tweener]
+/// running it will clamp and report [is_finished](Tweener::is_finished) once its last iteration
+/// is done. The Tweener's duration covers every iteration and gap, which [Tweener::repeat] works
+/// out for you:
+///
+/// ```
+/// # use tween::{Linear, Tweener};
+/// // pulse from 0 to 10 three times, with a one second break between pulses
+/// let mut tweener = Tweener::repeat(0, 10, 2.0, Linear, 3, 1.0);
+/// assert_eq!(tweener.duration, 8.0);
+///
+/// assert_eq!(tweener.move_to(1.0), 5);
+/// assert_eq!(tweener.move_to(2.5), 10); // held during the gap
+/// assert_eq!(tweener.move_to(4.0), 5);
+/// assert_eq!(tweener.iteration(), 1);
+///
+/// assert_eq!(tweener.move_to(8.0), 10);
+/// assert_eq!(tweener.iteration(), 2);
+/// assert!(tweener.is_finished());
+/// ```
+///
+/// During a gap, the Repeat holds the end value of the iteration before it.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Repeat<T: ?Sized> {
+    /// The number of times the tween plays. A count of `0` is treated as `1`.
+    pub count: u32,
+
+    /// The gap between each iteration, as a fraction of one iteration's length. For example, a gap
+    /// of `0.5` waits half as long as one iteration before starting the next.
+    pub gap: f32,
+
+    /// The actual underlying Tween.
+    pub tween: T,
+}
+
+impl<T> Repeat<T> {
+    /// Creates a new Repeat which plays a [Tween] `count` times, without gaps.
+    pub fn new(tween: T, count: u32) -> Self {
+        Self { count, gap: 0.0, tween }
+    }
+
+    /// Sets the gap between each iteration, as a fraction of one iteration's length.
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+impl<T: ?Sized> Repeat<T> {
+    /// Returns the index of the iteration playing at `percent` of the whole Repeat, starting from
+    /// `0`. During a gap, this is the iteration which just finished.
+    ///
+    /// The moment one iteration ends and the next begins belongs to the one which just ended, as
+    /// with a [Looper](crate::Looper).
+    pub fn iteration_at(&self, percent: f32) -> u32 {
+        self.locate(percent).0
+    }
+
+    /// Returns the iteration and the percent within that iteration, which runs past `1.0` during
+    /// the gap after it.
+    fn locate(&self, percent: f32) -> (u32, f32) {
+        let count = self.count.max(1);
+        let gap = self.gap.max(0.0);
+        let period = 1.0 + gap;
+
+        let position = percent * self.span();
+        if position <= 0.0 {
+            return (0, position);
+        }
+
+        let iteration = (math::ceil(position / period) as u32).clamp(1, count) - 1;
+        let local = position - iteration as f32 * period;
+
+        (iteration, local)
+    }
+
+    /// How many units of the inner tween's percent the whole repeat covers.
+    fn span(&self) -> f32 {
+        let gap = self.gap.max(0.0);
+
+        self.count.max(1) as f32 * (1.0 + gap) - gap
+    }
+}
+
+impl<Value, T> Tween<Value> for Repeat<T>
+where
+    Value: TweenValue,
+    T: Tween<Value> + ?Sized,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        let (_, percent) = self.locate(percent);
+
+        self.tween.tween(value_delta, percent.min(1.0))
+    }
+
+    #[inline]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        let (_, percent) = self.locate(percent);
+
+        // nothing moves during a gap
+        if percent > 1.0 {
+            return value_delta.scale(0.0);
+        }
+
+        self.tween.derivative(value_delta, percent).scale(self.span())
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.tween.is_finite()
+    }
+}
+
+impl<Value, Time, T> Tweener<Value, Time, Repeat<T>>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    /// Creates a new Tweener which plays `tween` `count` times, waiting `gap` between each
+    /// iteration. `iteration_duration` is the length of one iteration, not of the whole Tweener.
+    pub fn repeat(start: Value, end: Value, iteration_duration: Time, tween: T, count: u32, gap: Time) -> Self {
+        let count = count.max(1);
+
+        let mut duration = iteration_duration;
+        for _ in 1..count {
+            duration += gap + iteration_duration;
+        }
+
+        let repeat = Repeat::new(tween, count).with_gap(gap.to_f32() / iteration_duration.to_f32());
+
+        Self::new(start, end, duration, repeat)
+    }
+
+    /// Returns the index of the iteration playing at the Tweener's current time, starting from
+    /// `0`. See [Repeat::iteration_at].
+    pub fn iteration(&self) -> u32 {
+        let percent = self.current_time.to_f32() / self.duration.to_f32();
+
+        self.tween.iteration_at(percent.clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Linear};
+
+    #[test]
+    fn repeat() {
+        let mut tweener = Tweener::new(0, 2, 6, Repeat::new(Linear, 3));
+
+        assert!([0, 1, 2, 3, 4, 5, 6].map(|t| tweener.move_to(t)) == [0, 1, 2, 1, 2, 1, 2]);
+        assert_eq!(tweener.iteration(), 2);
+        assert!(tweener.is_finished());
+
+        // we clamp like any other finite tween
+        assert_eq!(tweener.move_to(9), 2);
+        assert_eq!(tweener.iteration(), 2);
+        assert_eq!(tweener.move_to(-1), 0);
+        assert_eq!(tweener.iteration(), 0);
+
+        let fixed = FixedTweener::new(0, 2, 4, Repeat::new(Linear, 2), 1);
+        assert!(fixed.eq([1, 2, 1, 2]));
+    }
+
+    #[test]
+    fn iterations() {
+        let repeat = Repeat::new(Linear, 4).with_gap(1.0);
+
+        // four iterations and three gaps, so each takes a seventh of the whole
+        let iterations = [0.0, 0.1, 1.0 / 7.0, 0.2, 0.35, 0.5, 0.9, 1.0].map(|pct| repeat.iteration_at(pct));
+        assert_eq!(iterations, [0, 0, 0, 0, 1, 1, 3, 3]);
+
+        assert_eq!(Repeat::new(Linear, 0).iteration_at(1.0), 0);
+    }
+
+    #[test]
+    fn gaps() {
+        let mut tweener = Tweener::repeat(0, 10, 10, Linear, 2, 5);
+        assert_eq!(tweener.duration, 25);
+
+        assert_eq!(tweener.move_to(5), 5);
+        assert_eq!(tweener.move_to(12), 10);
+        assert_eq!(tweener.iteration(), 0);
+        assert_eq!(tweener.move_to(20), 5);
+        assert_eq!(tweener.iteration(), 1);
+        assert_eq!(tweener.move_to(25), 10);
+        assert!(tweener.is_finished());
+    }
+
+    #[test]
+    fn derivative() {
+        use crate::QuadIn;
+
+        // three quad ins with a half gap take four times as long as one
+        let mut repeat = Repeat::new(QuadIn, 3).with_gap(0.5);
+        assert_eq!(repeat.derivative(1.0, 0.125), QuadIn.derivative(4.0, 0.5));
+        assert_eq!(repeat.derivative(1.0, 0.3), 0.0);
+        assert_eq!(repeat.derivative(1.0, 0.5), QuadIn.derivative(4.0, 0.5));
+    }
+
+    #[test]
+    fn finite() {
+        use crate::{Extrapolator, Looper};
+
+        assert!(Tween::<f32>::is_finite(&Repeat::new(Linear, 3)));
+        assert!(!Tween::<f32>::is_finite(&Repeat::new(Looper::new(Linear), 3)));
+
+        // a Tweener doesn't clamp or finish a Repeat of an infinite tween
+        let mut tweener = Tweener::new(0, 10, 10, Repeat::new(Extrapolator::new(Linear), 2));
+        tweener.move_to(15);
+        assert_eq!(tweener.current_time, 15);
+        assert!(!tweener.is_finished());
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{Tween, TweenValue};
+
+/// A [Reverse] is a wrapper around a [Tween] which plays it backwards in time, from its end value
+/// to its start value.
+///
+/// Where the wrapped tween gives `f(t)`, a Reverse gives `f(1 - t)`. This lets one [Tween] serve
+/// both directions of a transition, such as a panel sliding in and then sliding back out along the
+/// same curve:
+///
+/// ```
+/// # use tween::{Linear, Reverse, Tweener};
+/// let mut slide_out = Tweener::new(0, 100, 10, Reverse::new(Linear));
+///
+/// assert_eq!(slide_out.move_to(0), 100);
+/// assert_eq!(slide_out.move_to(3), 70);
+/// assert_eq!(slide_out.move_to(10), 0);
+/// ```
+///
+/// If you instead want the tween to still go from start to end, but with the shape of its curve
+/// flipped, use a [Mirror].
+///
+/// A Reverse is finite only if the tween it wraps is, so it can wrap a [Looper](crate::Looper) or
+/// an [Extrapolator](crate::Extrapolator) too.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Reverse<T: ?Sized>(pub T);
+
+impl<T> Reverse<T> {
+    /// Creates a new Reverse around a [Tween].
+    pub fn new(tween: T) -> Self {
+        Self(tween)
+    }
+}
+
+impl<Value, T> Tween<Value> for Reverse<T>
+where
+    Value: TweenValue,
+    T: Tween<Value> + ?Sized,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.0.tween(value_delta, 1.0 - percent)
+    }
+
+    #[inline(always)]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.0.derivative(value_delta, 1.0 - percent).scale(-1.0)
+    }
+
+    #[inline(always)]
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+}
+
+/// A [Mirror] is a wrapper around a [Tween] which flips the shape of its curve, turning an `In`
+/// curve into its corresponding `Out` curve, and an `Out` curve into its `In`.
+///
+/// Where the wrapped tween gives `f(t)`, a Mirror gives `1 - f(1 - t)`. It still goes from the
+/// start value to the end value, so any easing you write yourself gets its opposite for free:
+///
+/// ```
+/// # use tween::{CubicIn, CubicOut, Mirror, Tween};
+/// for percent in [0.0, 0.25, 0.5, 0.75, 1.0] {
+///     let mirrored = Mirror::new(CubicIn).tween(100.0f32, percent);
+///     assert!((mirrored - CubicOut.tween(100.0, percent)).abs() < 0.001);
+/// }
+/// ```
+///
+/// A Mirror is finite only if the tween it wraps is.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Mirror<T: ?Sized>(pub T);
+
+impl<T> Mirror<T> {
+    /// Creates a new Mirror around a [Tween].
+    pub fn new(tween: T) -> Self {
+        Self(tween)
+    }
+}
+
+impl<Value, T> Tween<Value> for Mirror<T>
+where
+    Value: TweenValue,
+    T: Tween<Value> + ?Sized,
+{
+    #[inline(always)]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta - self.0.tween(value_delta, 1.0 - percent)
+    }
+
+    #[inline(always)]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        // both flips cancel out
+        self.0.derivative(value_delta, 1.0 - percent)
+    }
+
+    #[inline(always)]
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BackIn, BackOut, Extrapolator, FixedTweener, Linear, Looper, QuadIn, QuadOut, Tweener};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn reverse() {
+        let mut tweener = Tweener::new(0, 4, 4, Linear).reversed();
+        assert_eq!(tweener.move_to(0), 4);
+        assert_eq!(tweener.move_to(1), 3);
+        assert_eq!(tweener.move_to(4), 0);
+        assert!(tweener.is_finished());
+
+        // reversing twice gets us back where we started
+        let mut tweener = Tweener::new(0.0f32, 1.0, 1.0, Reverse(Reverse(QuadIn)));
+        assert_eq!(tweener.move_to(0.3), QuadIn.tween(1.0, 0.3));
+
+        let fixed = FixedTweener::new(0, 2, 2, Reverse(Linear), 1);
+        assert!(fixed.eq([1, 0]));
+    }
+
+    #[test]
+    fn mirror() {
+        let mut tweener = Tweener::new(0.0f32, 10.0, 10.0, QuadIn).mirrored();
+        for time in 0..=10 {
+            let time = time as f32;
+            assert_relative_eq!(
+                tweener.move_to(time),
+                QuadOut.tween(10.0, time / 10.0),
+                epsilon = 0.0001
+            );
+        }
+
+        assert_relative_eq!(
+            Mirror(BackIn).tween(1.0f32, 0.2),
+            BackOut.tween(1.0, 0.2),
+            epsilon = 0.0001
+        );
+        assert_eq!(Mirror(Mirror(QuadIn)).tween(1.0f32, 0.25), QuadIn.tween(1.0, 0.25));
+    }
+
+    #[test]
+    fn finite() {
+        assert!(Tween::<f32>::is_finite(&Reverse(Linear)));
+        assert!(!Tween::<f32>::is_finite(&Reverse(Looper(Linear))));
+        assert!(!Tween::<f32>::is_finite(&Mirror(Looper(Linear))));
+
+        let mut tweener = Tweener::new(0, 4, 4, Mirror(Extrapolator(Linear)));
+        assert_eq!(tweener.move_to(5), 5);
+        assert!(!tweener.is_finished());
+    }
+
+    #[test]
+    fn derivative() {
+        for i in 1..10 {
+            let percent = i as f32 / 10.0;
+
+            assert_eq!(
+                Reverse(QuadIn).derivative(1.0, percent),
+                -QuadIn.derivative(1.0, 1.0 - percent)
+            );
+            assert_eq!(
+                Mirror(QuadIn).derivative(1.0, percent),
+                QuadIn.derivative(1.0, 1.0 - percent)
+            );
+        }
+    }
+}
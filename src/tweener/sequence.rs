@@ -0,0 +1,292 @@
+use crate::{CurrentTimeState, Tween, TweenTime, TweenValue, Tweener};
+use alloc::vec::Vec;
+
+/// A [Sequence] is an ordered list of [Tweener]s played back-to-back, where each segment begins
+/// at the value the previous segment ended at.
+///
+/// A `Sequence` exposes the same `move_to` and `move_by` methods as a [Tweener], but over the
+/// entire length of every segment combined. Time carries across segment boundaries, so a large
+/// `move_by` delta will skip over whole segments without losing any leftover time.
+///
+/// ```
+/// # use tween::{Sequence, Linear};
+/// let mut sequence = Sequence::new(0).then(10, 10, Linear).then(0, 5, Linear);
+///
+/// assert_eq!(sequence.move_by(5), 5);
+/// // this delta crosses into the second segment
+/// assert_eq!(sequence.move_by(8), 4);
+/// assert_eq!(sequence.move_by(2), 0);
+/// assert!(sequence.is_finished());
+/// ```
+///
+/// ## Erased Sequences
+///
+/// Every segment of a `Sequence` shares the same [Tween] type. To use different easings in each
+/// segment, box the tweens, just like with a [Tweener]:
+///
+/// ```
+/// # use tween::{BoxedSequence, Linear, SineIn};
+/// let mut sequence: BoxedSequence<i32, i32> = BoxedSequence::new(0)
+///     .then(100, 10, Box::new(Linear))
+///     .then(0, 10, Box::new(SineIn));
+///
+/// assert_eq!(sequence.move_to(5), 50);
+/// ```
+///
+/// ## Clamping
+///
+/// Like a [Tweener], a `Sequence` clamps its output to its first value before it starts, and to
+/// its last value once it has finished (unless the first or last segments hold unbounded tweens).
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord)]
+pub struct Sequence<Value, Time, T> {
+    /// The current time of the Sequence, from the start of its first segment. You can change this
+    /// value at will without running the Sequence, or change it with `move_by`.
+    pub current_time: Time,
+
+    start: Value,
+    segments: Vec<Tweener<Value, Time, T>>,
+}
+
+/// A [Sequence] whose segments are boxed, so each segment can use a different [Tween].
+pub type BoxedSequence<Value, Time> = Sequence<Value, Time, alloc::boxed::Box<dyn Tween<Value>>>;
+
+impl<Value, Time, T> Sequence<Value, Time, T>
+where
+    Time: TweenTime,
+    Value: TweenValue,
+    T: Tween<Value>,
+{
+    /// Creates a new, empty [Sequence] which begins at `start`. Add segments to it with
+    /// [then](Self::then) or [push](Self::push).
+    pub fn new(start: Value) -> Self {
+        Self {
+            current_time: Time::ZERO,
+            start,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Adds a new segment to the end of the Sequence, which tweens from the current final value
+    /// to `end` over `duration`, and returns the Sequence.
+    pub fn then(mut self, end: Value, duration: Time, tween: T) -> Self {
+        self.push(end, duration, tween);
+        self
+    }
+
+    /// Adds a new segment to the end of the Sequence, which tweens from the current final value
+    /// to `end` over `duration`.
+    pub fn push(&mut self, end: Value, duration: Time, tween: T) {
+        let start = self.final_value();
+        self.segments.push(Tweener::new(start, end, duration, tween));
+    }
+
+    /// Moves the Sequence to a given Time, returning the value of whichever segment that time
+    /// falls within.
+    ///
+    /// Giving [TweenTime::ZERO] to this function effectively resets a Sequence.
+    ///
+    /// Giving a negative time or a time beyond the Sequence's [duration](Self::duration) will move
+    /// the Sequence there, but the output will be clamped just like a [Tweener]'s.
+    #[inline]
+    pub fn move_to(&mut self, position: Time) -> Value {
+        self.current_time = position;
+
+        match self.locate(position) {
+            Some((index, local_time)) => self.segments[index].move_to(local_time),
+            None => self.start,
+        }
+    }
+
+    /// Drives the [Sequence] forward X steps in time.
+    ///
+    /// If the delta carries the Sequence past the end of a segment, the remaining time is applied
+    /// to the following segments.
+    #[inline]
+    pub fn move_by(&mut self, delta: Time) -> Value {
+        self.current_time += delta;
+
+        self.move_to(self.current_time)
+    }
+
+    /// The initial value the Sequence starts at.
+    #[inline]
+    pub fn initial_value(&self) -> Value {
+        self.start
+    }
+
+    /// The final value the Sequence should end at, which is the end value of its last segment.
+    #[inline]
+    pub fn final_value(&self) -> Value {
+        self.segments.last().map(|v| v.final_value()).unwrap_or(self.start)
+    }
+
+    /// The total duration of the Sequence, which is the sum of the durations of its segments.
+    pub fn duration(&self) -> Time {
+        self.segments.iter().fold(Time::ZERO, |acc, v| acc + v.duration)
+    }
+
+    /// The segments of this Sequence, in order.
+    pub fn segments(&self) -> &[Tweener<Value, Time, T>] {
+        &self.segments
+    }
+
+    /// The index of the segment that the Sequence's [current_time](Self::current_time) falls
+    /// within, or `None` if the Sequence has no segments.
+    pub fn current_segment(&self) -> Option<usize> {
+        self.locate(self.current_time).map(|(index, _)| index)
+    }
+
+    /// Returns `true` is the Sequence's [current_time] is greater than or equal to `0`.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_started(&self) -> bool {
+        self.current_time_state() != CurrentTimeState::Waiting
+    }
+
+    /// Returns `true` is the Sequence's [current_time] is greater than or equal to its total
+    /// duration.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_finished(&self) -> bool {
+        self.current_time_state() == CurrentTimeState::Finished
+    }
+
+    /// Returns `true` is the Sequence's [current_time] is greater than or equal to `0` but less
+    /// than its total duration.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_valid(&self) -> bool {
+        self.current_time_state() == CurrentTimeState::Valid
+    }
+
+    /// Returns `CurrentTimeState` based on the Sequence's [current_time].
+    ///
+    /// If the first segment holds an unbounded tween (like [Looper](crate::Looper)), the
+    /// Sequence is never `Waiting`. Likewise, if the last segment holds an unbounded tween, the
+    /// Sequence is never `Finished`.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn current_time_state(&self) -> CurrentTimeState {
+        let Some((index, local_time)) = self.locate(self.current_time) else {
+            return if self.current_time < Time::ZERO {
+                CurrentTimeState::Waiting
+            } else {
+                CurrentTimeState::Finished
+            };
+        };
+
+        let segment = &self.segments[index];
+        if !segment.tween.is_finite() {
+            return CurrentTimeState::Valid;
+        }
+
+        if index == 0 && local_time < Time::ZERO {
+            CurrentTimeState::Waiting
+        } else if index == self.segments.len() - 1 && local_time >= segment.duration {
+            CurrentTimeState::Finished
+        } else {
+            CurrentTimeState::Valid
+        }
+    }
+
+    /// Finds the segment which `position` falls within, and the time local to that segment.
+    ///
+    /// Negative times are given to the first segment, and times beyond the end of the Sequence
+    /// are given to the last segment, so that those segments can clamp (or not) as they see fit.
+    fn locate(&self, position: Time) -> Option<(usize, Time)> {
+        let last = self.segments.len().checked_sub(1)?;
+
+        if position < Time::ZERO {
+            return Some((0, position));
+        }
+
+        let mut segment_start = Time::ZERO;
+        for (index, segment) in self.segments[..last].iter().enumerate() {
+            let segment_end = segment_start + segment.duration;
+            if position < segment_end {
+                return Some((index, position - segment_start));
+            }
+
+            segment_start = segment_end;
+        }
+
+        Some((last, position - segment_start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Looper, SineIn};
+
+    #[test]
+    fn sequence() {
+        let mut sequence = Sequence::new(0).then(10, 10, Linear).then(0, 10, Linear);
+
+        let values: Vec<_> = (0..20).map(|_| sequence.move_by(2)).collect();
+        assert_eq!(*values, [2, 4, 6, 8, 10, 8, 6, 4, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(sequence.is_finished());
+        assert_eq!(sequence.duration(), 20);
+    }
+
+    #[test]
+    fn sequence_carries_time() {
+        let mut sequence = Sequence::new(0.0)
+            .then(10.0, 1.0, Linear)
+            .then(20.0, 1.0, Linear)
+            .then(30.0, 1.0, Linear);
+
+        assert_eq!(sequence.move_by(0.5), 5.0);
+        assert_eq!(sequence.current_segment(), Some(0));
+
+        // this skips the entire second segment
+        assert_eq!(sequence.move_by(2.0), 25.0);
+        assert_eq!(sequence.current_segment(), Some(2));
+        assert!(sequence.is_valid());
+
+        assert_eq!(sequence.move_by(10.0), 30.0);
+        assert!(sequence.is_finished());
+
+        assert_eq!(sequence.move_to(1.5), 15.0);
+    }
+
+    #[test]
+    fn sequence_clamps() {
+        let mut sequence = Sequence::new(5).then(10, 5, Linear);
+
+        assert_eq!(sequence.move_to(-3), 5);
+        assert!(!sequence.is_started());
+
+        assert_eq!(sequence.move_to(100), 10);
+        assert!(sequence.is_finished());
+    }
+
+    #[test]
+    fn sequence_empty() {
+        let mut sequence: Sequence<i32, i32, Linear> = Sequence::new(3);
+
+        assert_eq!(sequence.move_by(1), 3);
+        assert_eq!(sequence.final_value(), 3);
+        assert_eq!(sequence.current_segment(), None);
+        assert!(sequence.is_finished());
+    }
+
+    #[test]
+    fn sequence_unbounded() {
+        let mut sequence = Sequence::new(0).then(2, 2, Looper::new(Linear));
+
+        assert_eq!(sequence.move_to(3), 1);
+        assert!(sequence.is_valid());
+    }
+
+    #[test]
+    fn boxed_sequence() {
+        let mut sequence: BoxedSequence<f32, f32> = BoxedSequence::new(0.0)
+            .then(10.0, 1.0, alloc::boxed::Box::new(Linear))
+            .then(0.0, 1.0, alloc::boxed::Box::new(SineIn));
+
+        assert_eq!(sequence.move_to(0.5), 5.0);
+        assert_eq!(sequence.move_to(1.5), 10.0 - SineIn.tween(10.0, 0.5));
+        assert_eq!(sequence.move_to(2.0), 0.0);
+    }
+}
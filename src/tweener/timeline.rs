@@ -0,0 +1,558 @@
+use crate::{CurrentTimeState, Sequence, Tween, TweenTime, TweenValue, Tweener};
+use alloc::{string::String, vec::Vec};
+
+/// A [TimelineChild] is anything which can be placed within a [Timeline].
+///
+/// This is implemented for [Tweener], [Sequence], and [Timeline] itself, so timelines can be
+/// nested within each other.
+pub trait TimelineChild<Time> {
+    /// The length of time this child runs for once it has started.
+    fn duration(&self) -> Time;
+
+    /// Moves this child to the given time, relative to its own start.
+    fn seek(&mut self, time: Time);
+}
+
+impl<Value, Time, T> TimelineChild<Time> for Tweener<Value, Time, T>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    fn duration(&self) -> Time {
+        self.duration
+    }
+
+    /// This only sets the Tweener's `current_time` -- to get the value there, call `move_to`
+    /// with it.
+    fn seek(&mut self, time: Time) {
+        self.current_time = time;
+    }
+}
+
+impl<Value, Time, T> TimelineChild<Time> for Sequence<Value, Time, T>
+where
+    Value: TweenValue,
+    Time: TweenTime,
+    T: Tween<Value>,
+{
+    fn duration(&self) -> Time {
+        Sequence::duration(self)
+    }
+
+    /// This only sets the Sequence's `current_time` -- to get the value there, call `move_to`
+    /// with it.
+    fn seek(&mut self, time: Time) {
+        self.current_time = time;
+    }
+}
+
+impl<Time, C> TimelineChild<Time> for Timeline<Time, C>
+where
+    Time: TweenTime,
+    C: TimelineChild<Time>,
+{
+    fn duration(&self) -> Time {
+        Timeline::duration(self)
+    }
+
+    fn seek(&mut self, time: Time) {
+        self.move_to(time);
+    }
+}
+
+impl<Time, C> TimelineChild<Time> for alloc::boxed::Box<C>
+where
+    C: TimelineChild<Time> + ?Sized,
+{
+    fn duration(&self) -> Time {
+        (**self).duration()
+    }
+
+    fn seek(&mut self, time: Time) {
+        (**self).seek(time)
+    }
+}
+
+/// A [Timeline] places many children (usually [Tweener]s) along a single clock.
+///
+/// Each child is added at a [Position], which can either be an absolute time or be relative to
+/// the rest of the timeline, such as "0.2 seconds before the previous child ends", or "at the
+/// label `intro`". Moving the timeline with `move_to` or `move_by` moves every child to its own
+/// local time, and you can seek anywhere, including backwards.
+///
+/// ```
+/// # use tween::{Position, Timeline, Tweener};
+/// let mut timeline = Timeline::new();
+///
+/// let fade = timeline.add(Tweener::linear(0.0, 1.0, 1.0), Position::end());
+/// // slide in slightly before the fade finishes...
+/// let slide = timeline.add(
+///     Tweener::linear(-10.0, 0.0, 1.0),
+///     Position::previous_end().before(0.25),
+/// );
+/// // and pop once the slide is done.
+/// timeline.add_label("pop", Position::end());
+/// let pop = timeline.add(Tweener::linear(1.0, 1.5, 0.5), Position::label("pop"));
+///
+/// assert_eq!(timeline.start_time(slide), 0.75);
+/// assert_eq!(timeline.start_time(pop), 1.75);
+/// assert_eq!(timeline.duration(), 2.25);
+///
+/// timeline.move_to(1.25);
+///
+/// // children only have their time set, so we read their value out with `move_to`.
+/// let child = &mut timeline[slide];
+/// assert_eq!(child.move_to(child.current_time), -5.0);
+///
+/// let child = &mut timeline[fade];
+/// assert_eq!(child.move_to(child.current_time), 1.0);
+/// ```
+///
+/// Every child of a timeline has the same type. To place different kinds of children into one
+/// Timeline, box them as `Box<dyn TimelineChild<Time>>`, or use your own enum.
+///
+/// Children which haven't started yet are held at their own time `0`. Use
+/// [child_state](Self::child_state) to check whether a child has started or finished.
+#[derive(Debug, PartialEq, Clone, PartialOrd, Eq, Ord)]
+pub struct Timeline<Time, C> {
+    /// The current time of the Timeline. You can change this value at will without moving any
+    /// children, or change it with `move_to` and `move_by`.
+    pub current_time: Time,
+
+    entries: Vec<(Time, C)>,
+    labels: Vec<(String, Time)>,
+}
+
+impl<Time, C> Timeline<Time, C>
+where
+    Time: TweenTime,
+    C: TimelineChild<Time>,
+{
+    /// Creates a new, empty Timeline.
+    pub fn new() -> Self {
+        Self {
+            current_time: Time::ZERO,
+            entries: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    /// Adds a child to the Timeline at the given [Position], returning an id for it.
+    ///
+    /// The child is immediately moved to wherever the Timeline's current time falls within it.
+    pub fn add(&mut self, mut child: C, position: Position<'_, Time>) -> TimelineId {
+        let start = self.resolve(position);
+        child.seek(Self::local_time(self.current_time, start));
+
+        self.entries.push((start, child));
+
+        TimelineId(self.entries.len() - 1)
+    }
+
+    /// Adds a label at the given [Position], which later children can be placed relative to.
+    /// Returns the time the label was placed at.
+    ///
+    /// Adding a label with the same name as an existing label moves that label.
+    pub fn add_label(&mut self, name: impl Into<String>, position: Position<'_, Time>) -> Time {
+        let name = name.into();
+        let time = self.resolve(position);
+
+        match self.labels.iter_mut().find(|(label, _)| *label == name) {
+            Some((_, label_time)) => *label_time = time,
+            None => self.labels.push((name, time)),
+        }
+
+        time
+    }
+
+    /// Returns the time of the given label, if it exists.
+    pub fn label(&self, name: &str) -> Option<Time> {
+        self.labels
+            .iter()
+            .find_map(|(label, time)| (label == name).then_some(*time))
+    }
+
+    /// Moves the Timeline to a given Time, seeking every child to its own local time.
+    ///
+    /// This can move the Timeline both forwards and backwards.
+    pub fn move_to(&mut self, position: Time) {
+        self.current_time = position;
+
+        for (start, child) in self.entries.iter_mut() {
+            child.seek(Self::local_time(position, *start));
+        }
+    }
+
+    /// Drives the [Timeline] forward X steps in time.
+    pub fn move_by(&mut self, delta: Time) {
+        self.current_time += delta;
+
+        self.move_to(self.current_time)
+    }
+
+    /// The total duration of the Timeline, which is the time its last child finishes.
+    pub fn duration(&self) -> Time {
+        self.entries.iter().fold(Time::ZERO, |acc, (start, child)| {
+            let end = *start + child.duration();
+            if end > acc { end } else { acc }
+        })
+    }
+
+    /// The time the given child starts at.
+    pub fn start_time(&self, id: TimelineId) -> Time {
+        self.entries[id.0].0
+    }
+
+    /// The time the given child ends at.
+    pub fn end_time(&self, id: TimelineId) -> Time {
+        let (start, child) = &self.entries[id.0];
+        *start + child.duration()
+    }
+
+    /// Returns the child with the given id, if it exists.
+    pub fn get(&self, id: TimelineId) -> Option<&C> {
+        self.entries.get(id.0).map(|(_, child)| child)
+    }
+
+    /// Returns the child with the given id mutably, if it exists.
+    pub fn get_mut(&mut self, id: TimelineId) -> Option<&mut C> {
+        self.entries.get_mut(id.0).map(|(_, child)| child)
+    }
+
+    /// Iterates over every child and its id, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (TimelineId, &C)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, (_, child))| (TimelineId(index), child))
+    }
+
+    /// Iterates over every child and its id mutably, in the order they were added.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (TimelineId, &mut C)> {
+        self.entries
+            .iter_mut()
+            .enumerate()
+            .map(|(index, (_, child))| (TimelineId(index), child))
+    }
+
+    /// The number of children in the Timeline.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the Timeline has no children.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the `CurrentTimeState` of the given child, based on the Timeline's
+    /// [current_time](Self::current_time).
+    pub fn child_state(&self, id: TimelineId) -> CurrentTimeState {
+        if self.current_time < self.start_time(id) {
+            CurrentTimeState::Waiting
+        } else if self.current_time >= self.end_time(id) {
+            CurrentTimeState::Finished
+        } else {
+            CurrentTimeState::Valid
+        }
+    }
+
+    /// Returns `true` is the Timeline's [current_time] is greater than or equal to `0`.
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_started(&self) -> bool {
+        self.current_time_state() != CurrentTimeState::Waiting
+    }
+
+    /// Returns `true` is the Timeline's [current_time] is greater than or equal to its
+    /// [duration](Self::duration).
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_finished(&self) -> bool {
+        self.current_time_state() == CurrentTimeState::Finished
+    }
+
+    /// Returns `true` is the Timeline's [current_time] is greater than or equal to `0` but less
+    /// than its [duration](Self::duration).
+    ///
+    /// [current_time]: Self::current_time
+    pub fn is_valid(&self) -> bool {
+        self.current_time_state() == CurrentTimeState::Valid
+    }
+
+    /// Returns `CurrentTimeState` based on the Timeline's [current_time].
+    ///
+    /// [current_time]: Self::current_time
+    pub fn current_time_state(&self) -> CurrentTimeState {
+        if self.current_time < Time::ZERO {
+            CurrentTimeState::Waiting
+        } else if self.current_time >= self.duration() {
+            CurrentTimeState::Finished
+        } else {
+            CurrentTimeState::Valid
+        }
+    }
+
+    fn resolve(&self, position: Position<'_, Time>) -> Time {
+        let previous = self.entries.last();
+
+        let anchor = match position.anchor {
+            Anchor::Start => Time::ZERO,
+            Anchor::End => self.duration(),
+            Anchor::PreviousStart => previous.map(|(start, _)| *start).unwrap_or(Time::ZERO),
+            Anchor::PreviousEnd => previous
+                .map(|(start, child)| *start + child.duration())
+                .unwrap_or(Time::ZERO),
+            Anchor::Label(name) => self.label(name).unwrap_or_else(|| self.duration()),
+        };
+
+        match position.offset {
+            Offset::After(offset) if Time::ZERO < anchor + offset => anchor + offset,
+            Offset::After(_) => Time::ZERO,
+            Offset::Before(offset) if offset < anchor => anchor - offset,
+            Offset::Before(_) => Time::ZERO,
+        }
+    }
+
+    fn local_time(position: Time, start: Time) -> Time {
+        if position >= start {
+            position - start
+        } else {
+            Time::ZERO
+        }
+    }
+}
+
+impl<Time, C> Default for Timeline<Time, C>
+where
+    Time: TweenTime,
+    C: TimelineChild<Time>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Time, C> core::ops::Index<TimelineId> for Timeline<Time, C> {
+    type Output = C;
+
+    fn index(&self, id: TimelineId) -> &Self::Output {
+        &self.entries[id.0].1
+    }
+}
+
+impl<Time, C> core::ops::IndexMut<TimelineId> for Timeline<Time, C> {
+    fn index_mut(&mut self, id: TimelineId) -> &mut Self::Output {
+        &mut self.entries[id.0].1
+    }
+}
+
+/// An id for a child within a [Timeline], returned by [Timeline::add].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimelineId(usize);
+
+impl TimelineId {
+    /// The index of the child within its Timeline, in the order it was added.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A [Position] describes where a child or label is placed within a [Timeline].
+///
+/// A Position is made of an anchor, such as the end of the Timeline or a label, and an optional
+/// offset [after](Self::after) or [before](Self::before) that anchor. Positions which would
+/// place a child before `0` place it at `0` instead.
+///
+/// ```
+/// # use tween::Position;
+/// // at exactly 1.5
+/// let _ = Position::at(1.5);
+/// // 0.2 before the previous child ends
+/// let _ = Position::previous_end().before(0.2);
+/// // alongside the previous child
+/// let _ = Position::<f32>::previous_start();
+/// // half a second after the label "intro"
+/// let _ = Position::label("intro").after(0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position<'a, Time> {
+    anchor: Anchor<'a>,
+    offset: Offset<Time>,
+}
+
+impl<'a, Time: TweenTime> Position<'a, Time> {
+    /// A Position at an absolute time in the Timeline. Negative times are placed at `0`.
+    pub fn at(time: Time) -> Self {
+        Self::new(Anchor::Start).after(time)
+    }
+
+    /// A Position at the current end of the Timeline. This is where children are usually placed.
+    pub fn end() -> Self {
+        Self::new(Anchor::End)
+    }
+
+    /// A Position at the start of the most recently added child, or `0` if there isn't one.
+    pub fn previous_start() -> Self {
+        Self::new(Anchor::PreviousStart)
+    }
+
+    /// A Position at the end of the most recently added child, or `0` if there isn't one.
+    pub fn previous_end() -> Self {
+        Self::new(Anchor::PreviousEnd)
+    }
+
+    /// A Position at the given label. If the label doesn't exist, this is the same as
+    /// [end](Self::end).
+    pub fn label(name: &'a str) -> Self {
+        Self::new(Anchor::Label(name))
+    }
+
+    /// Offsets this Position later in time by `offset`.
+    pub fn after(mut self, offset: Time) -> Self {
+        self.offset = Offset::After(offset);
+        self
+    }
+
+    /// Offsets this Position earlier in time by `offset`.
+    pub fn before(mut self, offset: Time) -> Self {
+        self.offset = Offset::Before(offset);
+        self
+    }
+
+    fn new(anchor: Anchor<'a>) -> Self {
+        Self {
+            anchor,
+            offset: Offset::After(Time::ZERO),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Anchor<'a> {
+    Start,
+    End,
+    PreviousStart,
+    PreviousEnd,
+    Label(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Offset<Time> {
+    After(Time),
+    Before(Time),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Tween};
+    use alloc::boxed::Box;
+
+    fn value<T: Tween<i32>>(tweener: &mut Tweener<i32, i32, T>) -> i32 {
+        tweener.move_to(tweener.current_time)
+    }
+
+    #[test]
+    fn positions() {
+        let mut timeline = Timeline::new();
+
+        let a = timeline.add(Tweener::linear(0, 10, 10), Position::end());
+        let b = timeline.add(Tweener::linear(0, 10, 10), Position::end());
+        let c = timeline.add(Tweener::linear(0, 10, 10), Position::previous_start().after(5));
+        let d = timeline.add(Tweener::linear(0, 10, 10), Position::previous_end().before(2));
+        let e = timeline.add(Tweener::linear(0, 10, 10), Position::at(3));
+        let f = timeline.add(Tweener::linear(0, 10, 10), Position::previous_start().before(100));
+
+        assert_eq!(timeline.start_time(a), 0);
+        assert_eq!(timeline.start_time(b), 10);
+        assert_eq!(timeline.start_time(c), 15);
+        assert_eq!(timeline.start_time(d), 23);
+        assert_eq!(timeline.start_time(e), 3);
+        assert_eq!(timeline.start_time(f), 0);
+        assert_eq!(timeline.duration(), 33);
+
+        // negative offsets are clamped to `0` too
+        let g = timeline.add(Tweener::linear(0, 10, 10), Position::at(-3));
+        let h = timeline.add(Tweener::linear(0, 10, 10), Position::previous_end().after(-20));
+        assert_eq!(timeline.start_time(g), 0);
+        assert_eq!(timeline.start_time(h), 0);
+    }
+
+    #[test]
+    fn labels() {
+        let mut timeline = Timeline::new();
+
+        timeline.add(Tweener::linear(0, 10, 10), Position::end());
+        assert_eq!(timeline.add_label("intro", Position::end()), 10);
+        assert_eq!(timeline.label("intro"), Some(10));
+        assert_eq!(timeline.label("outro"), None);
+
+        let a = timeline.add(Tweener::linear(0, 10, 10), Position::label("intro").after(2));
+        let b = timeline.add(Tweener::linear(0, 10, 10), Position::label("outro"));
+
+        assert_eq!(timeline.start_time(a), 12);
+        assert_eq!(timeline.start_time(b), 22);
+
+        // moving a label
+        timeline.add_label("intro", Position::at(1));
+        assert_eq!(timeline.label("intro"), Some(1));
+    }
+
+    #[test]
+    fn seeking() {
+        let mut timeline = Timeline::new();
+
+        let a = timeline.add(Tweener::linear(0, 10, 10), Position::end());
+        let b = timeline.add(Tweener::linear(0, 10, 10), Position::previous_end().before(5));
+
+        timeline.move_by(7);
+        assert_eq!(value(&mut timeline[a]), 7);
+        assert_eq!(value(&mut timeline[b]), 2);
+        assert_eq!(timeline.child_state(a), CurrentTimeState::Valid);
+
+        timeline.move_to(3);
+        assert_eq!(value(&mut timeline[a]), 3);
+        assert_eq!(value(&mut timeline[b]), 0);
+        assert_eq!(timeline.child_state(b), CurrentTimeState::Waiting);
+
+        timeline.move_to(100);
+        assert_eq!(value(&mut timeline[a]), 10);
+        assert_eq!(value(&mut timeline[b]), 10);
+        assert_eq!(timeline.child_state(a), CurrentTimeState::Finished);
+        assert!(timeline.is_finished());
+
+        timeline.move_to(14);
+        assert_eq!(value(&mut timeline[b]), 9);
+        assert!(timeline.is_valid());
+    }
+
+    #[test]
+    fn nested() {
+        let mut inner = Timeline::new();
+        inner.add(Tweener::linear(0, 10, 10), Position::at(5));
+
+        let mut outer = Timeline::new();
+        outer.add(
+            Box::new(Tweener::linear(0, 10, 10)) as Box<dyn TimelineChild<i32>>,
+            Position::end(),
+        );
+        let nested = outer.add(Box::new(inner) as Box<dyn TimelineChild<i32>>, Position::end());
+
+        assert_eq!(outer.duration(), 25);
+        assert_eq!(outer.start_time(nested), 10);
+
+        outer.move_to(18);
+        assert_eq!(outer[nested].duration(), 15);
+
+        let mut inner: Timeline<i32, Tweener<i32, i32, Linear>> = Timeline::new();
+        let leaf = inner.add(Tweener::linear(0, 10, 10), Position::at(5));
+        let mut outer = Timeline::new();
+        let nested = outer.add(inner, Position::at(10));
+
+        outer.move_to(18);
+        assert_eq!(outer[nested].current_time, 8);
+        assert_eq!(value(&mut outer[nested][leaf]), 3);
+    }
+}
@@ -0,0 +1,186 @@
+use super::oscillator::fold;
+use crate::{Tween, TweenValue};
+
+/// A [Yoyo] is a wrapper around two [Tween]s which places them into an infinite ping pong: one
+/// tween carries the value from the start to the end, and the other carries it back again.
+///
+/// This is like an [Oscillator](crate::Oscillator), which retraces the same tween on the way back,
+/// but each leg can have its own easing. A Yoyo can also hold at either end, and start partway
+/// through its cycle:
+///
+/// ```
+/// # use tween::{BounceOut, Linear, Tweener, Yoyo};
+/// // rise linearly for a second, wait half a second, then fall back down with a bounce
+/// let yoyo = Yoyo::new(Linear, BounceOut).with_holds(0.0, 0.5);
+/// let mut tweener = Tweener::new(0.0f32, 100.0, 1.0, yoyo);
+///
+/// assert_eq!(tweener.move_to(0.5), 50.0);
+/// assert_eq!(tweener.move_to(1.25), 100.0);
+/// assert_eq!(tweener.move_to(2.5), 0.0);
+/// assert_eq!(tweener.move_to(3.0), 50.0);
+/// ```
+///
+/// The Tweener's duration is the length of one leg, and the holds are given as fractions of it.
+/// The return leg plays its tween forwards in time from the end value to the start value, so a
+/// bounce on the way back bounces against the start. `Yoyo::new(tween, Mirror(tween))` plays
+/// the same as `Oscillator(tween)`.
+///
+/// Like an Oscillator, a Yoyo never finishes.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Yoyo<A, B: ?Sized> {
+    /// How long to hold at the start value before leaving it, as a fraction of one leg.
+    pub hold_start: f32,
+
+    /// How long to hold at the end value before returning, as a fraction of one leg.
+    pub hold_end: f32,
+
+    /// How far through its cycle the Yoyo starts, as a fraction of the whole cycle. Giving many
+    /// Yoyos different phases keeps them from moving in lockstep.
+    pub phase: f32,
+
+    /// The tween from the start value to the end value.
+    pub there: A,
+
+    /// The tween from the end value back to the start value.
+    pub back: B,
+}
+
+impl<A, B> Yoyo<A, B> {
+    /// Creates a new Yoyo which goes out with `there` and returns with `back`.
+    pub fn new(there: A, back: B) -> Self {
+        Self {
+            hold_start: 0.0,
+            hold_end: 0.0,
+            phase: 0.0,
+            there,
+            back,
+        }
+    }
+
+    /// Sets how long to hold at the start and end values, as fractions of one leg.
+    pub fn with_holds(mut self, hold_start: f32, hold_end: f32) -> Self {
+        self.hold_start = hold_start;
+        self.hold_end = hold_end;
+        self
+    }
+
+    /// Sets how far through its cycle the Yoyo starts, as a fraction of the whole cycle.
+    pub fn with_phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+}
+
+impl<A, B: ?Sized> Yoyo<A, B> {
+    /// Maps a percent, where each leg is `1.0` long, into the `0..2` percent of an [Oscillator],
+    /// squeezing out the holds. Also returns whether `percent` is within a hold.
+    ///
+    /// [Oscillator]: crate::Oscillator
+    fn unhold(&self, percent: f32) -> (f32, bool) {
+        let hold_start = self.hold_start.max(0.0);
+        let hold_end = self.hold_end.max(0.0);
+        let cycle = 2.0 + hold_start + hold_end;
+
+        let mut position = (percent + self.phase * cycle) % cycle;
+        if position < 0.0 {
+            position += cycle;
+        }
+
+        if position <= 1.0 {
+            (position, false)
+        } else if position <= 1.0 + hold_end {
+            (1.0, true)
+        } else if position <= 2.0 + hold_end {
+            (position - hold_end, false)
+        } else {
+            (0.0, true)
+        }
+    }
+}
+
+impl<Value, A, B> Tween<Value> for Yoyo<A, B>
+where
+    Value: TweenValue,
+    A: Tween<Value>,
+    B: Tween<Value> + ?Sized,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        match fold(self.unhold(percent).0) {
+            (percent, false) => self.there.tween(value_delta, percent),
+            (percent, true) => value_delta - self.back.tween(value_delta, 1.0 - percent),
+        }
+    }
+
+    #[inline]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        let (percent, holding) = self.unhold(percent);
+        if holding {
+            return value_delta.scale(0.0);
+        }
+
+        match fold(percent) {
+            (percent, false) => self.there.derivative(value_delta, percent),
+            (percent, true) => self.back.derivative(value_delta, 1.0 - percent).scale(-1.0),
+        }
+    }
+
+    fn is_finite(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Linear, Mirror, Oscillator, QuadIn, QuadOut, Tweener};
+
+    #[test]
+    fn legs() {
+        let mut tweener = Tweener::new(0, 4, 4, Yoyo::new(Linear, QuadIn));
+
+        let values = [0, 2, 4, 5, 6, 7, 8, 10].map(|t| tweener.move_to(t));
+        assert_eq!(values, [0, 2, 4, 4, 3, 2, 0, 2]);
+        assert!(!tweener.is_finished());
+
+        // mirroring the same tween on the way back is just an oscillator
+        let mut yoyo = Yoyo::new(QuadOut, Mirror(QuadOut));
+        let mut oscillator = Oscillator(QuadOut);
+        for i in 0..=40 {
+            let percent = i as f32 / 10.0;
+            let difference = yoyo.tween(1.0f32, percent) - oscillator.tween(1.0, percent);
+            assert!(difference.abs() < 0.0001, "{}", percent);
+        }
+    }
+
+    #[test]
+    fn holds() {
+        let tweener = FixedTweener::new(0, 2, 2, Yoyo::new(Linear, Linear).with_holds(1.0, 0.5), 1);
+
+        // a cycle is a leg, a half leg hold, a leg, and a full leg hold
+        assert!(tweener.take(12).eq([1, 2, 2, 1, 0, 0, 0, 1, 2, 2, 1, 0]));
+    }
+
+    #[test]
+    fn phase() {
+        let mut yoyo = Yoyo::new(Linear, Linear).with_phase(0.25);
+        assert_eq!(yoyo.tween(10, 0.0), 5);
+        assert_eq!(yoyo.tween(10, 0.5), 10);
+        assert_eq!(yoyo.tween(10, 1.5), 0);
+
+        // phases wrap around, and negative percents are fine too
+        let mut yoyo = Yoyo::new(Linear, Linear).with_phase(-0.75);
+        assert_eq!(yoyo.tween(10, 0.0), 5);
+        assert_eq!(yoyo.tween(10, -0.5), 0);
+    }
+
+    #[test]
+    fn derivative() {
+        let mut yoyo = Yoyo::new(QuadIn, QuadOut).with_holds(0.0, 1.0);
+
+        assert_eq!(yoyo.derivative(1.0f32, 0.5), QuadIn.derivative(1.0, 0.5));
+        assert_eq!(yoyo.derivative(1.0f32, 1.5), 0.0);
+        assert_eq!(yoyo.derivative(1.0f32, 2.25), -QuadOut.derivative(1.0, 0.25));
+    }
+}
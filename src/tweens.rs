@@ -4,6 +4,9 @@ pub use linear::Linear;
 mod cubic;
 pub use cubic::{CubicIn, CubicInOut, CubicOut};
 
+mod cubic_bezier;
+pub use cubic_bezier::CubicBezierEase;
+
 mod sine;
 pub use sine::{SineIn, SineInOut, SineOut};
 
@@ -23,10 +26,43 @@ mod circ;
 pub use circ::{CircIn, CircInOut, CircOut};
 
 mod back;
-pub use back::{BackIn, BackInOut, BackOut};
+pub use back::{BackIn, BackInOut, BackOut, CustomBackIn, CustomBackInOut, CustomBackOut};
 
 mod elastic;
-pub use elastic::{ElasticIn, ElasticInOut, ElasticOut};
+pub use elastic::{CustomElasticIn, CustomElasticInOut, CustomElasticOut, ElasticIn, ElasticInOut, ElasticOut};
+
+mod spring;
+pub use spring::Spring;
+
+mod steps;
+pub use steps::{StepPosition, Steps};
 
 mod bounce;
-pub use bounce::{BounceIn, BounceInOut, BounceOut};
+pub use bounce::{BounceIn, BounceInOut, BounceOut, CustomBounceIn, CustomBounceInOut, CustomBounceOut};
+
+mod easing;
+pub use easing::{Easing, ParseEasingError};
+
+#[cfg(feature = "alloc")]
+mod registry;
+#[cfg(feature = "alloc")]
+pub use registry::{CustomEasing, EasingRegistry, RegisteredEasing};
+
+/// Checks a tween's [derivative](crate::Tween::derivative) against a finite difference of its
+/// values. We stay off the exact ends, and any kinks, where the two sides differ.
+#[cfg(test)]
+pub(crate) fn assert_derivative_matches(mut tween: impl crate::Tween<f32>) {
+    const STEP: f32 = 0.0005;
+
+    for i in 0..40 {
+        let percent = (i as f32 + 0.3) / 40.0;
+        let estimate = (tween.tween(1.0, percent + STEP) - tween.tween(1.0, percent - STEP)) / (STEP * 2.0);
+
+        approx::assert_relative_eq!(
+            tween.derivative(1.0, percent),
+            estimate,
+            max_relative = 0.01,
+            epsilon = 0.01
+        );
+    }
+}
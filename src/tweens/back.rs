@@ -4,7 +4,7 @@ const BACK_CONST: f32 = 1.70158;
 
 /// This is another magic constant for the back in out tween.
 /// Where it comes from, I do not know!
-const BACK_IN_OUT_CONST: f32 = BACK_CONST * 1.525;
+const BACK_IN_OUT_SCALE: f32 = 1.525;
 
 declare_tween! {
     /// A tween that goes out and then back in a bit. Go [here](https://easings.net/#easeInBack) for a visual demonstration.
@@ -17,9 +17,15 @@ declare_tween! {
     pub fn back_in_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
-        let scalar = percent * percent * ((BACK_CONST + 1.0) * percent - BACK_CONST);
+        CustomBackIn::default().tween(value_delta, percent)
+    }
 
-        value_delta.scale(scalar)
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomBackIn::default().derivative(value_delta, percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
     }
 }
 
@@ -34,10 +40,15 @@ declare_tween! {
     pub fn back_out_at;
 
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
-        let t = percent - 1.0;
-        let scalar = t * t * ((BACK_CONST + 1.0) * t + BACK_CONST) + 1.0;
+        CustomBackOut::default().tween(value_delta, percent)
+    }
 
-        value_delta.scale(scalar)
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomBackOut::default().derivative(value_delta, percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
     }
 }
 
@@ -51,19 +62,273 @@ declare_tween! {
     /// Creates a new [BackInOut] Tweener at the given time.
     pub fn back_in_out_at;
 
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomBackInOut::default().tween(value_delta, percent)
+    }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomBackInOut::default().derivative(value_delta, percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
+    }
+}
+
+/// A [BackIn] tween with an adjustable overshoot.
+///
+/// ```
+/// # use tween::{BackIn, CustomBackIn, Tween};
+/// assert_eq!(CustomBackIn::DEFAULT.overshoot, 1.70158);
+/// assert_eq!(
+///     CustomBackIn::default().tween(10.0, 0.3),
+///     BackIn.tween(10.0, 0.3)
+/// );
+///
+/// // pulls back twice as far before it goes
+/// const STRONG: CustomBackIn = CustomBackIn::new(3.4);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomBackIn {
+    /// How far the tween pulls back. The default, `1.70158`, pulls back by about 10%, and `0.0`
+    /// doesn't pull back at all.
+    pub overshoot: f32,
+}
+
+impl CustomBackIn {
+    /// The overshoot used by [BackIn].
+    pub const DEFAULT: Self = Self::new(BACK_CONST);
+
+    /// Creates a new [CustomBackIn] with the given overshoot.
+    pub const fn new(overshoot: f32) -> Self {
+        Self { overshoot }
+    }
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(back_in(percent, self.overshoot))
+    }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(back_in_slope(percent, self.overshoot))
+    }
+}
+
+/// A [BackOut] tween with an adjustable overshoot.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomBackOut {
+    /// How far the tween overshoots. The default, `1.70158`, overshoots by about 10%, and `0.0`
+    /// doesn't overshoot at all.
+    pub overshoot: f32,
+}
+
+impl CustomBackOut {
+    /// The overshoot used by [BackOut].
+    pub const DEFAULT: Self = Self::new(BACK_CONST);
+
+    /// Creates a new [CustomBackOut] with the given overshoot.
+    pub const fn new(overshoot: f32) -> Self {
+        Self { overshoot }
+    }
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let t = percent - 1.0;
+        let scalar = t * t * ((self.overshoot + 1.0) * t + self.overshoot) + 1.0;
+
+        value_delta.scale(scalar)
+    }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(back_out_slope(percent - 1.0, self.overshoot))
+    }
+}
+
+/// A [BackInOut] tween with an adjustable overshoot.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomBackInOut {
+    /// How far the tween pulls back and overshoots. This uses the same scale as [CustomBackIn]
+    /// and [CustomBackOut], so the default is also `1.70158`, which is stretched to cover each
+    /// half of the tween.
+    pub overshoot: f32,
+}
+
+impl CustomBackInOut {
+    /// The overshoot used by [BackInOut].
+    pub const DEFAULT: Self = Self::new(BACK_CONST);
+
+    /// Creates a new [CustomBackInOut] with the given overshoot.
+    pub const fn new(overshoot: f32) -> Self {
+        Self { overshoot }
+    }
+
+    /// Run the given Tween with a new time.
+    #[inline]
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, mut percent: f32) -> Value {
+        let overshoot = self.overshoot * BACK_IN_OUT_SCALE;
         percent *= 2.0;
 
         let scalar = if percent < 1.0 {
-            percent * percent * ((BACK_IN_OUT_CONST + 1.0) * percent - BACK_IN_OUT_CONST)
+            back_in(percent, overshoot)
         } else {
             let t = percent - 2.0;
 
-            t * t * ((BACK_IN_OUT_CONST + 1.0) * t + BACK_IN_OUT_CONST) + 2.0
+            t * t * ((overshoot + 1.0) * t + overshoot) + 2.0
         };
 
         value_delta.scale(scalar / 2.0)
     }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let overshoot = self.overshoot * BACK_IN_OUT_SCALE;
+        let percent = percent * 2.0;
+
+        // each half is squashed into half the time, which cancels out halving its height
+        let slope = if percent < 1.0 {
+            back_in_slope(percent, overshoot)
+        } else {
+            back_out_slope(percent - 2.0, overshoot)
+        };
+
+        value_delta.scale(slope)
+    }
+}
+
+#[inline(always)]
+fn back_in(percent: f32, overshoot: f32) -> f32 {
+    percent * percent * ((overshoot + 1.0) * percent - overshoot)
+}
+
+#[inline(always)]
+fn back_in_slope(percent: f32, overshoot: f32) -> f32 {
+    percent * (3.0 * (overshoot + 1.0) * percent - 2.0 * overshoot)
+}
+
+/// The slope of the back out curve, where `t` is the percent minus `1.0`.
+#[inline(always)]
+fn back_out_slope(t: f32, overshoot: f32) -> f32 {
+    t * (3.0 * (overshoot + 1.0) * t + 2.0 * overshoot)
 }
 
+macro_rules! impl_custom_back {
+    ($($name:ident),*) => {
+        $(
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::DEFAULT
+                }
+            }
+
+            impl<Value> crate::Tween<Value> for $name
+            where
+                Value: crate::TweenValue,
+            {
+                #[inline]
+                fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+                    self.tween(value_delta, percent)
+                }
+
+                #[inline]
+                fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+                    self.derivative(value_delta, percent)
+                }
+            }
+        )*
+    };
+}
+
+impl_custom_back!(CustomBackIn, CustomBackOut, CustomBackInOut);
+
 test_tween!(Back);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tween;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn custom_defaults() {
+        // golden values from the old closed form Back formulas, which had an overshoot of `1.70158`
+        const PERCENTS: [f32; 9] = [0.0, 0.1, 0.25, 0.4, 0.5, 0.6, 0.75, 0.9, 1.0];
+        const IN: [f32; 9] = [
+            0.0,
+            -0.014314221,
+            -0.064136565,
+            -0.09935168,
+            -0.087697506,
+            -0.02902751,
+            0.1825903,
+            0.5911719,
+            1.0,
+        ];
+        const OUT: [f32; 9] = [
+            0.0, 0.40882808, 0.8174097, 1.0290275, 1.0876975, 1.0993516, 1.0641365, 1.0143142, 1.0,
+        ];
+        const IN_OUT: [f32; 9] = [
+            0.0,
+            -0.037518553,
+            -0.09968184,
+            0.08992585,
+            0.5,
+            0.91007423,
+            1.0996819,
+            1.0375186,
+            1.0,
+        ];
+
+        fn check(mut tween: impl Tween<f32>, expected: [f32; 9]) {
+            for (percent, expected) in PERCENTS.into_iter().zip(expected) {
+                assert_relative_eq!(
+                    tween.tween(1.0, percent),
+                    expected,
+                    max_relative = 0.0001,
+                    epsilon = 0.00001
+                );
+            }
+        }
+
+        check(CustomBackIn::default(), IN);
+        check(CustomBackOut::default(), OUT);
+        check(CustomBackInOut::default(), IN_OUT);
+        check(BackIn, IN);
+        check(BackOut, OUT);
+        check(BackInOut, IN_OUT);
+    }
+
+    #[test]
+    fn custom_overshoot() {
+        const NONE: CustomBackOut = CustomBackOut::new(0.0);
+        const STRONG: CustomBackOut = CustomBackOut::new(4.0);
+
+        let peak = |mut tween: CustomBackOut| {
+            (0..=100)
+                .map(|i| tween.tween(1.0, i as f32 / 100.0))
+                .fold(0.0, f32::max)
+        };
+
+        assert_eq!(peak(NONE), 1.0);
+        assert!(peak(STRONG) > peak(CustomBackOut::DEFAULT));
+        assert!(peak(CustomBackOut::DEFAULT) > 1.0);
+
+        let mut strong = CustomBackIn::new(4.0);
+        assert!(strong.tween(1.0, 0.3) < BackIn.tween(1.0, 0.3));
+        assert_eq!(strong.tween(1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn custom_derivative() {
+        crate::tweens::assert_derivative_matches(CustomBackInOut::new(4.0));
+    }
+}
@@ -37,6 +37,13 @@ declare_tween!(
         value_delta - v
     }
 
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(bounce_out_slope(1.0 - percent))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
+    }
 );
 
 declare_tween!(
@@ -66,6 +73,14 @@ declare_tween!(
 
         value_delta.scale(multip)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(bounce_out_slope(percent))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
+    }
 );
 
 declare_tween!(
@@ -119,6 +134,316 @@ declare_tween!(
         }
     }
 
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        if percent < 0.5 {
+            value_delta.scale(bounce_out_slope(1.0 - percent * 2.0))
+        } else {
+            value_delta.scale(bounce_out_slope((percent - 0.5) * 2.0))
+        }
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
+    }
 );
 
+/// A [BounceOut] tween with an adjustable number of bounces and restitution.
+///
+/// The tween is shaped like a ball dropped onto the end value: it falls, lands, and then bounces
+/// `bounces` more times, with each bounce's speed (and so its length) scaled by `restitution`.
+/// [BounceOut] is 3 bounces with a restitution of `0.5`, which is the [DEFAULT](Self::DEFAULT).
+///
+/// ```
+/// # use tween::{BounceOut, CustomBounceOut, Tween};
+/// // two soft bounces
+/// let mut soft = CustomBounceOut::new(2, 0.3);
+/// // five hard ones
+/// let mut hard = CustomBounceOut::new(5, 0.7);
+///
+/// assert_eq!(soft.tween(1.0, 1.0), 1.0);
+/// assert_eq!(hard.tween(1.0, 1.0), 1.0);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomBounceOut {
+    /// How many times the tween bounces after first reaching the end value.
+    pub bounces: u32,
+
+    /// How much of its speed the tween keeps after each bounce. This should be between `0.0`,
+    /// which doesn't bounce at all, and `1.0`, which bounces all the way back to the start.
+    pub restitution: f32,
+}
+
+/// A [BounceIn] tween with an adjustable number of bounces and restitution. See
+/// [CustomBounceOut] for more information.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomBounceIn {
+    /// How many times the tween bounces before leaving the start value.
+    pub bounces: u32,
+
+    /// How much of its speed the tween keeps after each bounce, between `0.0` and `1.0`.
+    pub restitution: f32,
+}
+
+/// A [BounceInOut] tween with an adjustable number of bounces and restitution. See
+/// [CustomBounceOut] for more information.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomBounceInOut {
+    /// How many times each half of the tween bounces.
+    pub bounces: u32,
+
+    /// How much of its speed the tween keeps after each bounce, between `0.0` and `1.0`.
+    pub restitution: f32,
+}
+
+impl CustomBounceOut {
+    /// The bounces and restitution used by [BounceOut].
+    pub const DEFAULT: Self = Self::new(3, 0.5);
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(bounce_out(percent, self.bounces, self.restitution))
+    }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(custom_bounce_out_slope(percent, self.bounces, self.restitution))
+    }
+}
+
+impl CustomBounceIn {
+    /// The bounces and restitution used by [BounceIn].
+    pub const DEFAULT: Self = Self::new(3, 0.5);
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(1.0 - bounce_out(1.0 - percent, self.bounces, self.restitution))
+    }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(custom_bounce_out_slope(1.0 - percent, self.bounces, self.restitution))
+    }
+}
+
+impl CustomBounceInOut {
+    /// The bounces and restitution used by [BounceInOut].
+    pub const DEFAULT: Self = Self::new(3, 0.5);
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let scalar = if percent < 0.5 {
+            (1.0 - bounce_out(1.0 - percent * 2.0, self.bounces, self.restitution)) * 0.5
+        } else {
+            bounce_out((percent - 0.5) * 2.0, self.bounces, self.restitution) * 0.5 + 0.5
+        };
+
+        value_delta.scale(scalar)
+    }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let slope = if percent < 0.5 {
+            custom_bounce_out_slope(1.0 - percent * 2.0, self.bounces, self.restitution)
+        } else {
+            custom_bounce_out_slope((percent - 0.5) * 2.0, self.bounces, self.restitution)
+        };
+
+        value_delta.scale(slope)
+    }
+}
+
+/// The slope of the classic bounce out curve used by [BounceOut].
+fn bounce_out_slope(percent: f32) -> f32 {
+    let t = if percent < STAGE_ZERO {
+        percent
+    } else if percent < STAGE_ONE {
+        percent - 1.5 / 2.75
+    } else if percent < STAGE_TWO {
+        percent - 2.25 / 2.75
+    } else {
+        percent - 2.625 / 2.75
+    };
+
+    2.0 * MAGIC * t
+}
+
+/// The bounce out curve, made of a falling half parabola and then a full parabola for each bounce.
+///
+/// We measure time in units of the fall, which takes `1.0`. Each bounce then takes twice as long
+/// as its speed, and its height is the square of its speed.
+fn bounce_out(percent: f32, bounces: u32, restitution: f32) -> f32 {
+    if percent >= 1.0 {
+        return 1.0;
+    }
+
+    let restitution = restitution.clamp(0.0, 1.0);
+    let time = percent * bounce_total(bounces, restitution);
+    if time < 1.0 {
+        return time * time;
+    }
+
+    let mut start = 1.0;
+    let mut speed = 1.0;
+    for _ in 0..bounces {
+        speed *= restitution;
+
+        let end = start + 2.0 * speed;
+        if time < end {
+            let t = time - (start + speed);
+            return 1.0 - speed * speed + t * t;
+        }
+
+        start = end;
+    }
+
+    1.0
+}
+
+/// The slope of [bounce_out]. Each parabola's slope is twice its time, which we then stretch by
+/// the total time, since that's how much faster than the percent the time runs.
+fn custom_bounce_out_slope(percent: f32, bounces: u32, restitution: f32) -> f32 {
+    if percent >= 1.0 {
+        return 0.0;
+    }
+
+    let restitution = restitution.clamp(0.0, 1.0);
+    let total = bounce_total(bounces, restitution);
+    let time = percent * total;
+    if time < 1.0 {
+        return 2.0 * time * total;
+    }
+
+    let mut start = 1.0;
+    let mut speed = 1.0;
+    for _ in 0..bounces {
+        speed *= restitution;
+
+        let end = start + 2.0 * speed;
+        if time < end {
+            return 2.0 * (time - (start + speed)) * total;
+        }
+
+        start = end;
+    }
+
+    0.0
+}
+
+/// The total time of the bounce out curve, in units of the fall.
+fn bounce_total(bounces: u32, restitution: f32) -> f32 {
+    let mut total = 1.0;
+    let mut speed = 1.0;
+    for _ in 0..bounces {
+        speed *= restitution;
+        total += 2.0 * speed;
+    }
+
+    total
+}
+
+macro_rules! impl_custom_bounce {
+    ($($name:ident),*) => {
+        $(
+            impl $name {
+                /// Creates a new tween with the given number of bounces and restitution.
+                pub const fn new(bounces: u32, restitution: f32) -> Self {
+                    Self { bounces, restitution }
+                }
+            }
+
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::DEFAULT
+                }
+            }
+
+            impl<Value> crate::Tween<Value> for $name
+            where
+                Value: crate::TweenValue,
+            {
+                #[inline]
+                fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+                    self.tween(value_delta, percent)
+                }
+
+                #[inline]
+                fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+                    self.derivative(value_delta, percent)
+                }
+            }
+        )*
+    };
+}
+
+impl_custom_bounce!(CustomBounceIn, CustomBounceOut, CustomBounceInOut);
+
 test_tween!(Bounce);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn custom_defaults() {
+        for i in 0..=100 {
+            let percent = i as f32 / 100.0;
+
+            assert_relative_eq!(
+                CustomBounceIn::default().tween(7.0, percent),
+                BounceIn.tween(7.0, percent),
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                CustomBounceOut::default().tween(7.0, percent),
+                BounceOut.tween(7.0, percent),
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                CustomBounceInOut::default().tween(7.0, percent),
+                BounceInOut.tween(7.0, percent),
+                epsilon = 0.00001
+            );
+        }
+    }
+
+    #[test]
+    fn custom_bounces() {
+        for (bounces, restitution) in [(0, 0.5), (2, 0.3), (5, 0.7), (3, 0.0), (4, 1.0)] {
+            let mut tween = CustomBounceOut::new(bounces, restitution);
+            assert_eq!(tween.tween(1.0, 0.0), 0.0);
+            assert_eq!(tween.tween(1.0, 1.0), 1.0);
+
+            // continuous, and never passes the end value
+            let mut last = 0.0f32;
+            for i in 1..=10000 {
+                let value = tween.tween(1.0, i as f32 / 10000.0);
+                assert!(value <= 1.0);
+                assert!((value - last).abs() < 0.01, "jumped from {} to {}", last, value);
+                last = value;
+            }
+        }
+
+        // more bounces leave less of the tween for the fall, so it falls faster
+        let mut few = CustomBounceOut::new(1, 0.5);
+        let mut many = CustomBounceOut::new(6, 0.5);
+        assert!(many.tween(1.0, 0.3) > few.tween(1.0, 0.3));
+    }
+
+    #[test]
+    fn custom_derivative() {
+        crate::tweens::assert_derivative_matches(CustomBounceInOut::new(5, 0.7));
+
+        // resting on the end value
+        assert_eq!(CustomBounceOut::new(2, 0.5).derivative(1.0, 1.0), 0.0);
+    }
+}
@@ -1,5 +1,10 @@
+use crate::math;
+
 declare_tween!(
     /// A circular tween in. Go [here](https://easings.net/#easeInCirc) for a visual demonstration.
+    ///
+    /// This ends on a vertical edge of the circle, so its [derivative](crate::Tween::derivative) is
+    /// unbounded there: it's infinite at `1.0`, and very large just before.
     pub struct CircIn;
 
     /// Creates a new [CircIn] Tweener.
@@ -17,10 +22,25 @@ declare_tween!(
 
         value_delta.scale(scalar)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(percent / math::sqrt(1.0 - percent * percent))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| {
+            let t = 1.0 - progress;
+
+            math::sqrt(1.0 - t * t)
+        })
+    }
 );
 
 declare_tween!(
     /// A circular tween out. Go [here](https://easings.net/#easeOutCirc) for a visual demonstration.
+    ///
+    /// This starts on a vertical edge of the circle, so its [derivative](crate::Tween::derivative)
+    /// is unbounded there: it's infinite at `0.0`, and very large just after.
     pub struct CircOut;
 
     /// Creates a new [CircOut] Tweener.
@@ -40,10 +60,23 @@ declare_tween!(
 
         value_delta.scale(scalar)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let t = percent - 1.0;
+        value_delta.scale(-t / math::sqrt(1.0 - t * t))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| 1.0 - math::sqrt(1.0 - progress * progress))
+    }
 );
 
 declare_tween!(
     /// A circular tween in and out. Go [here](https://easings.net/#easeInOutCirc) for a visual demonstration.
+    ///
+    /// This passes a vertical edge of the circle halfway through, so its
+    /// [derivative](crate::Tween::derivative) is unbounded there: it's infinite at `0.5`, and very
+    /// large either side of it.
     pub struct CircInOut;
 
     /// Creates a new [CircInOut] Tweener.
@@ -77,6 +110,28 @@ declare_tween!(
 
         value_delta.scale(scalar / 2.0)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let percent = percent * 2.0;
+
+        let slope = if percent < 1.0 {
+            percent / math::sqrt(1.0 - percent * percent)
+        } else {
+            let p = percent - 2.0;
+
+            -p / math::sqrt(1.0 - p * p)
+        };
+        value_delta.scale(slope)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| {
+            let t = 2.0 * progress - 1.0;
+            let half = math::sqrt(1.0 - t * t) / 2.0;
+
+            if progress < 0.5 { half } else { 1.0 - half }
+        })
+    }
 );
 
 test_tween!(Circ);
@@ -11,6 +11,14 @@ declare_tween!(
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
         value_delta.scale(percent * percent * percent)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(3.0 * percent * percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_in(progress, 3.0))
+    }
 );
 
 declare_tween!(
@@ -28,6 +36,15 @@ declare_tween!(
 
         value_delta.scale(percent * percent * percent + 1.0)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let t = percent - 1.0;
+        value_delta.scale(3.0 * t * t)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_out(progress, 3.0))
+    }
 );
 
 declare_tween!(
@@ -51,6 +68,23 @@ declare_tween!(
         };
         value_delta.scale(scalar / 2.0)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let percent = percent * 2.0;
+
+        let slope = if percent < 1.0 {
+            3.0 * percent * percent
+        } else {
+            let p = percent - 2.0;
+
+            3.0 * p * p
+        };
+        value_delta.scale(slope)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_in_out(progress, 3.0))
+    }
 );
 
 test_tween!(Cubic);
@@ -0,0 +1,351 @@
+/// How close the solved curve must be to the requested percent. Browsers use a similar epsilon.
+const EPSILON: f32 = 1e-6;
+
+/// How many Newton-Raphson steps we try before falling back to bisection.
+const NEWTON_ITERATIONS: usize = 8;
+
+/// How many bisection steps we allow. Each step halves the interval, so this is well below the
+/// precision of an `f32`.
+const BISECTION_ITERATIONS: usize = 32;
+
+/// A timing function tween, identical to CSS's `cubic-bezier(x1, y1, x2, y2)`.
+///
+/// Unlike the other tweens in this library, a `CubicBezierEase` holds data: the two control points
+/// of a cubic bezier curve which runs from `(0, 0)` to `(1, 1)`. The `x` axis of the curve is
+/// time, and the `y` axis is progress, so designers can hand over the same four numbers they use
+/// in a browser or in a tool like Figma.
+///
+/// ```
+/// # use tween::{CubicBezierEase, Tweener};
+/// let mut tweener = Tweener::new(0, 100, 10, CubicBezierEase::EASE);
+/// assert_eq!(tweener.move_to(5), 80);
+///
+/// let mut tweener =
+///     Tweener::cubic_bezier(0.0, 100.0, 1.0, CubicBezierEase::new(0.0, 1.0, 0.0, 1.0));
+/// assert!(tweener.move_to(0.25) > 50.0);
+/// ```
+///
+/// `x1` and `x2` must be within `[0, 1]` for the curve to be a function of time, so they are
+/// clamped to that range. `y1` and `y2` can be anything, and values outside of `[0, 1]` will make
+/// the tween overshoot.
+///
+/// Percents outside of `[0, 1]` (such as when wrapped in an [Extrapolator](crate::Extrapolator))
+/// are extended along the curve's tangent at either end, as browsers do.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CubicBezierEase {
+    /// The `x` (time) coordinate of the first control point.
+    pub x1: f32,
+    /// The `y` (progress) coordinate of the first control point.
+    pub y1: f32,
+    /// The `x` (time) coordinate of the second control point.
+    pub x2: f32,
+    /// The `y` (progress) coordinate of the second control point.
+    pub y2: f32,
+}
+
+impl CubicBezierEase {
+    /// CSS's `ease`, which is `cubic-bezier(0.25, 0.1, 0.25, 1.0)`. This is also the [Default].
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+
+    /// CSS's `ease-in`, which is `cubic-bezier(0.42, 0.0, 1.0, 1.0)`.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+
+    /// CSS's `ease-out`, which is `cubic-bezier(0.0, 0.0, 0.58, 1.0)`.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+
+    /// CSS's `ease-in-out`, which is `cubic-bezier(0.42, 0.0, 0.58, 1.0)`.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    /// Creates a new timing function from two control points, in the same order as CSS's
+    /// `cubic-bezier(x1, y1, x2, y2)`.
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.ease(percent))
+    }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.slope(percent))
+    }
+
+    /// Returns the progress, usually between `0` and `1`, at the given percent of time.
+    pub fn ease(&self, percent: f32) -> f32 {
+        if percent <= 0.0 {
+            return self.start_slope() * percent;
+        }
+
+        if percent >= 1.0 {
+            return 1.0 + self.end_slope() * (percent - 1.0);
+        }
+
+        let x = Polynomial::new(self.x1.clamp(0.0, 1.0), self.x2.clamp(0.0, 1.0));
+        let y = Polynomial::new(self.y1, self.y2);
+
+        y.sample(x.solve(percent))
+    }
+
+    /// Returns the slope of [ease](Self::ease) at the given percent of time, which is how much
+    /// progress is made per unit of time.
+    pub fn slope(&self, percent: f32) -> f32 {
+        if percent <= 0.0 {
+            return self.start_slope();
+        }
+
+        if percent >= 1.0 {
+            return self.end_slope();
+        }
+
+        let x = Polynomial::new(self.x1.clamp(0.0, 1.0), self.x2.clamp(0.0, 1.0));
+        let y = Polynomial::new(self.y1, self.y2);
+
+        let t = x.solve(percent);
+        let dx = x.derivative(t);
+        if dx == 0.0 {
+            return 0.0;
+        }
+
+        y.derivative(t) / dx
+    }
+
+    /// The slope of the curve's tangent at `(0, 0)`.
+    fn start_slope(&self) -> f32 {
+        let x1 = self.x1.clamp(0.0, 1.0);
+        let x2 = self.x2.clamp(0.0, 1.0);
+
+        if x1 > 0.0 {
+            self.y1 / x1
+        } else if self.y1 == 0.0 && x2 > 0.0 {
+            self.y2 / x2
+        } else {
+            0.0
+        }
+    }
+
+    /// The slope of the curve's tangent at `(1, 1)`.
+    fn end_slope(&self) -> f32 {
+        let x1 = self.x1.clamp(0.0, 1.0);
+        let x2 = self.x2.clamp(0.0, 1.0);
+
+        if x2 < 1.0 {
+            (self.y2 - 1.0) / (x2 - 1.0)
+        } else if self.y2 == 1.0 && x1 < 1.0 {
+            (self.y1 - 1.0) / (x1 - 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for CubicBezierEase {
+    fn default() -> Self {
+        Self::EASE
+    }
+}
+
+impl<Value> crate::Tween<Value> for CubicBezierEase
+where
+    Value: crate::TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+
+    #[inline]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.derivative(value_delta, percent)
+    }
+}
+
+impl<Value, Time> crate::Tweener<Value, Time, CubicBezierEase>
+where
+    Time: crate::TweenTime,
+    Value: crate::TweenValue,
+{
+    /// Creates a new [CubicBezierEase] Tweener.
+    pub fn cubic_bezier(start: Value, end: Value, duration: Time, ease: CubicBezierEase) -> Self {
+        Self::new(start, end, duration, ease)
+    }
+
+    /// Creates a new [CubicBezierEase] Tweener at the given time.
+    pub fn cubic_bezier_at(
+        start: Value,
+        end: Value,
+        duration: Time,
+        ease: CubicBezierEase,
+        current_time: Time,
+    ) -> Self {
+        Self::new_at(start, end, duration, ease, current_time)
+    }
+}
+
+impl<Value, Time> crate::FixedTweener<Value, Time, CubicBezierEase>
+where
+    Time: crate::TweenTime,
+    Value: crate::TweenValue,
+{
+    /// Creates a new [CubicBezierEase] Tweener.
+    pub fn cubic_bezier(start: Value, end: Value, duration: Time, ease: CubicBezierEase, delta: Time) -> Self {
+        Self::new(start, end, duration, ease, delta)
+    }
+
+    /// Creates a new [CubicBezierEase] Tweener at the given time.
+    pub fn cubic_bezier_at(
+        start: Value,
+        end: Value,
+        duration: Time,
+        ease: CubicBezierEase,
+        current_time: Time,
+        delta: Time,
+    ) -> Self {
+        Self::new_at(start, end, duration, ease, current_time, delta)
+    }
+}
+
+/// One axis of a cubic bezier from `0` to `1`, in polynomial form: `((a * t + b) * t + c) * t`.
+struct Polynomial {
+    a: f32,
+    b: f32,
+    c: f32,
+}
+
+impl Polynomial {
+    fn new(p1: f32, p2: f32) -> Self {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        let a = 1.0 - c - b;
+
+        Self { a, b, c }
+    }
+
+    fn sample(&self, t: f32) -> f32 {
+        ((self.a * t + self.b) * t + self.c) * t
+    }
+
+    fn derivative(&self, t: f32) -> f32 {
+        (3.0 * self.a * t + 2.0 * self.b) * t + self.c
+    }
+
+    /// Finds the `t` where this polynomial equals `x`. The caller must ensure `x` is within
+    /// `[0, 1]`.
+    fn solve(&self, x: f32) -> f32 {
+        // newton-raphson converges very quickly when the curve isn't flat...
+        let mut t = x;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = self.sample(t) - x;
+            if error.abs() < EPSILON {
+                return t;
+            }
+
+            let derivative = self.derivative(t);
+            if derivative.abs() < EPSILON {
+                break;
+            }
+
+            t -= error / derivative;
+        }
+
+        // ...but when it is, we fall back to bisection, which always converges.
+        let (mut low, mut high) = (0.0, 1.0);
+        t = x;
+        for _ in 0..BISECTION_ITERATIONS {
+            let sample = self.sample(t);
+            if (sample - x).abs() < EPSILON {
+                break;
+            }
+
+            if x > sample {
+                low = t;
+            } else {
+                high = t;
+            }
+
+            t = (low + high) / 2.0;
+        }
+
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn css_keywords() {
+        // reference values from a browser's `getComputedTiming`
+        assert_relative_eq!(CubicBezierEase::EASE.ease(0.5), 0.8024, epsilon = 0.0001);
+        assert_relative_eq!(CubicBezierEase::EASE_IN.ease(0.5), 0.3153, epsilon = 0.0001);
+        assert_relative_eq!(CubicBezierEase::EASE_OUT.ease(0.5), 0.6847, epsilon = 0.0001);
+        assert_relative_eq!(CubicBezierEase::EASE_IN_OUT.ease(0.5), 0.5, epsilon = 0.0001);
+
+        for ease in [
+            CubicBezierEase::EASE,
+            CubicBezierEase::EASE_IN,
+            CubicBezierEase::EASE_OUT,
+            CubicBezierEase::EASE_IN_OUT,
+        ] {
+            assert_eq!(ease.ease(0.0), 0.0);
+            assert_eq!(ease.ease(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn linear() {
+        let ease = CubicBezierEase::new(0.0, 0.0, 1.0, 1.0);
+
+        for i in 0..=100 {
+            let percent = i as f32 / 100.0;
+            assert_relative_eq!(ease.ease(percent), percent, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn flat_curve() {
+        // the x derivative is zero at both ends here, which trips up newton-raphson
+        let ease = CubicBezierEase::new(1.0, 0.0, 0.0, 1.0);
+
+        let mut last = 0.0;
+        for i in 0..=100 {
+            let value = ease.ease(i as f32 / 100.0);
+            assert!(value >= last);
+            last = value;
+        }
+        assert_relative_eq!(ease.ease(0.5), 0.5, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn overshoot_and_extrapolate() {
+        let ease = CubicBezierEase::new(0.5, -0.5, 0.5, 1.5);
+        assert!(ease.ease(0.1) < 0.0);
+        assert!(ease.ease(0.9) > 1.0);
+
+        assert_relative_eq!(ease.ease(-1.0), 1.0);
+        assert_relative_eq!(ease.ease(2.0), 0.0);
+    }
+
+    #[test]
+    fn tweener() {
+        let mut tweener = crate::Tweener::cubic_bezier(0, 100, 10, CubicBezierEase::EASE);
+        assert_eq!(tweener.move_to(5), 80);
+        assert_eq!(tweener.move_to(10), 100);
+    }
+
+    #[test]
+    fn slope() {
+        let ease = CubicBezierEase::new(0.3, -0.5, 0.6, 1.4);
+        crate::tweens::assert_derivative_matches(ease);
+
+        // and outside, it's the tangent at either end
+        assert_relative_eq!(ease.slope(-1.0), -0.5 / 0.3);
+        assert_relative_eq!(ease.slope(2.0), 0.4 / -0.4);
+    }
+}
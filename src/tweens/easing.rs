@@ -0,0 +1,247 @@
+use crate::{Tween, TweenValue};
+
+macro_rules! declare_easing {
+    ($($variant:ident => $name:literal, $css_name:literal;)*) => {
+        /// Every easing in this library which has no settings, as one enum, so easings can be chosen
+        /// at runtime, such as by name from a data file.
+        ///
+        /// An `Easing` is a [Tween] which runs the easing of the same name, so it can be used anywhere
+        /// those can:
+        ///
+        /// ```
+        /// # use tween::{Easing, Tweener};
+        /// let easing: Easing = "easeInOutCubic".parse().unwrap();
+        /// assert_eq!(easing, Easing::CubicInOut);
+        ///
+        /// let mut tweener = Tweener::new(0.0f32, 10.0, 1.0, easing);
+        /// assert_eq!(tweener.move_to(0.5), 5.0);
+        /// ```
+        ///
+        /// Names are parsed without caring about case, `_`, `-` or spaces, so `cubic_in_out`,
+        /// `CubicInOut` and the [easings.net](https://easings.net) style `easeInOutCubic` are all
+        /// [CubicInOut](Easing::CubicInOut). An `Easing` displays as the first of these, which is also
+        /// the name of its shortcut constructor, such as [Tweener::cubic_in_out](crate::Tweener::cubic_in_out).
+        ///
+        /// Easings which have settings, such as [CubicBezierEase](crate::CubicBezierEase) or
+        /// [Spring](crate::Spring), aren't included. To choose those (or your own) by name, use an
+        /// [EasingRegistry](crate::EasingRegistry).
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum Easing {
+            /// A [Linear](crate::Linear) tween. This is the default.
+            #[default]
+            Linear,
+            $(
+                #[doc = concat!("A [", stringify!($variant), "](crate::", stringify!($variant), ") tween.")]
+                $variant,
+            )*
+        }
+
+        impl Easing {
+            /// Every easing, in the order they're declared.
+            pub const ALL: [Self; 31] = [Self::Linear $(, Self::$variant)*];
+
+            /// Returns the name of the easing, such as `cubic_in_out`. This is also what it
+            /// displays as.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    Self::Linear => "linear",
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            /// Returns the [easings.net](https://easings.net) style name of the easing, such as
+            /// `easeInOutCubic`.
+            pub const fn css_name(self) -> &'static str {
+                match self {
+                    Self::Linear => "linear",
+                    $(Self::$variant => $css_name,)*
+                }
+            }
+
+            /// Run the given Tween with a new time.
+            #[inline]
+            pub fn tween<Value: TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+                match self {
+                    Self::Linear => crate::Linear.tween(value_delta, percent),
+                    $(Self::$variant => crate::$variant.tween(value_delta, percent),)*
+                }
+            }
+
+            /// Returns how fast the Tween is changing at the given time. See
+            /// [Tween::derivative].
+            #[inline]
+            pub fn derivative<Value: TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+                match self {
+                    Self::Linear => crate::Linear.derivative(value_delta, percent),
+                    $(Self::$variant => crate::$variant.derivative(value_delta, percent),)*
+                }
+            }
+
+            /// Returns the first percent at which the Tween reaches `value`. See
+            /// [Tween::inverse].
+            #[inline]
+            pub fn inverse<Value: TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+                match self {
+                    Self::Linear => crate::Linear.inverse(value_delta, value),
+                    $(Self::$variant => crate::$variant.inverse(value_delta, value),)*
+                }
+            }
+        }
+    };
+}
+
+declare_easing! {
+    QuadIn => "quad_in", "easeInQuad";
+    QuadOut => "quad_out", "easeOutQuad";
+    QuadInOut => "quad_in_out", "easeInOutQuad";
+    CubicIn => "cubic_in", "easeInCubic";
+    CubicOut => "cubic_out", "easeOutCubic";
+    CubicInOut => "cubic_in_out", "easeInOutCubic";
+    QuartIn => "quart_in", "easeInQuart";
+    QuartOut => "quart_out", "easeOutQuart";
+    QuartInOut => "quart_in_out", "easeInOutQuart";
+    QuintIn => "quint_in", "easeInQuint";
+    QuintOut => "quint_out", "easeOutQuint";
+    QuintInOut => "quint_in_out", "easeInOutQuint";
+    SineIn => "sine_in", "easeInSine";
+    SineOut => "sine_out", "easeOutSine";
+    SineInOut => "sine_in_out", "easeInOutSine";
+    ExpoIn => "expo_in", "easeInExpo";
+    ExpoOut => "expo_out", "easeOutExpo";
+    ExpoInOut => "expo_in_out", "easeInOutExpo";
+    CircIn => "circ_in", "easeInCirc";
+    CircOut => "circ_out", "easeOutCirc";
+    CircInOut => "circ_in_out", "easeInOutCirc";
+    BackIn => "back_in", "easeInBack";
+    BackOut => "back_out", "easeOutBack";
+    BackInOut => "back_in_out", "easeInOutBack";
+    ElasticIn => "elastic_in", "easeInElastic";
+    ElasticOut => "elastic_out", "easeOutElastic";
+    ElasticInOut => "elastic_in_out", "easeInOutElastic";
+    BounceIn => "bounce_in", "easeInBounce";
+    BounceOut => "bounce_out", "easeOutBounce";
+    BounceInOut => "bounce_in_out", "easeInOutBounce";
+}
+
+impl Easing {
+    /// Returns an iterator over every easing. See [ALL](Self::ALL).
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+}
+
+impl<Value> Tween<Value> for Easing
+where
+    Value: TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+
+    #[inline]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.derivative(value_delta, percent)
+    }
+
+    #[inline]
+    fn inverse(&mut self, value_delta: Value, value: Value) -> Option<f32>
+    where
+        Value: PartialOrd,
+    {
+        self.inverse(value_delta, value)
+    }
+}
+
+impl core::fmt::Display for Easing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl core::str::FromStr for Easing {
+    type Err = ParseEasingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|easing| same_name(s, easing.name()) || same_name(s, easing.css_name()))
+            .ok_or(ParseEasingError)
+    }
+}
+
+/// The error returned when parsing an [Easing] from a name it doesn't have.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Default)]
+#[non_exhaustive]
+pub struct ParseEasingError;
+
+impl core::fmt::Display for ParseEasingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown easing name")
+    }
+}
+
+impl core::error::Error for ParseEasingError {}
+
+/// Returns `true` if the two names are the same, ignoring case, `_`, `-` and spaces.
+pub(crate) fn same_name(a: &str, b: &str) -> bool {
+    normalize(a).eq(normalize(b))
+}
+
+/// Returns the characters of `name` which matter when comparing it to another. See [same_name].
+pub(crate) fn normalize(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .map(|c| c.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BounceOut, ElasticInOut, Tweener};
+
+    #[test]
+    fn names() {
+        for easing in Easing::iter() {
+            assert_eq!(easing.name().parse(), Ok(easing));
+            assert_eq!(easing.css_name().parse(), Ok(easing));
+        }
+
+        assert_eq!("easeInOutCubic".parse(), Ok(Easing::CubicInOut));
+        assert_eq!("cubic_in_out".parse(), Ok(Easing::CubicInOut));
+        assert_eq!("CubicInOut".parse(), Ok(Easing::CubicInOut));
+        assert_eq!("Bounce-Out".parse(), Ok(Easing::BounceOut));
+        assert_eq!("linear".parse(), Ok(Easing::Linear));
+        assert_eq!("cubic".parse::<Easing>(), Err(ParseEasingError));
+        assert_eq!("".parse::<Easing>(), Err(ParseEasingError));
+
+        // every name is different
+        for (i, a) in Easing::iter().enumerate() {
+            for b in Easing::iter().skip(i + 1) {
+                assert!(!same_name(a.name(), b.name()));
+                assert!(!same_name(a.css_name(), b.css_name()));
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display() {
+        assert_eq!(std::format!("{}", Easing::ElasticInOut), "elastic_in_out");
+        assert_eq!(Easing::ALL.len(), 31);
+        assert_eq!(Easing::default(), Easing::Linear);
+    }
+
+    #[test]
+    fn tweens() {
+        let mut tweener = Tweener::new(0.0f32, 10.0, 1.0, Easing::BounceOut);
+        assert_eq!(tweener.move_to(0.3), BounceOut.tween(10.0, 0.3));
+
+        let mut easing = Easing::ElasticInOut;
+        assert_eq!(easing.derivative(2.0f32, 0.4), ElasticInOut.derivative(2.0, 0.4));
+        assert_eq!(
+            Tween::<f32>::inverse(&mut easing, 2.0, 1.5),
+            ElasticInOut.inverse(2.0f32, 1.5)
+        );
+    }
+}
@@ -1,9 +1,15 @@
-use core::f32::consts::TAU;
+use crate::math;
+use core::f32::consts::{LN_2, TAU};
 
-const SIGMA: f32 = 0.075;
-const SIGMA_IN_OUT: f32 = 0.1125;
-const THREE_DOT_THREE_REPEATING: f32 = 10.0 / 3.0;
-const FORTY_FIVE: f32 = 2.222222;
+/// The period of the elastic in and out tweens.
+const PERIOD: f32 = 0.3;
+
+/// The period of the elastic in-out tween, which is stretched to cover each half.
+const PERIOD_IN_OUT: f32 = PERIOD * 1.5;
+
+/// The shift of the default elastic in-out tween. This is a quarter of its period, but it has
+/// always been rounded separately, so we keep it to match [ElasticInOut] exactly.
+const SHIFT_IN_OUT: f32 = 0.1125;
 
 declare_tween!(
     /// An elastic tween in. Go [here](https://easings.net/#easeInElastic) for a visual demonstration.
@@ -15,6 +21,115 @@ declare_tween!(
     /// Creates a new [ElasticIn] Tweener at the given time.
     pub fn elastic_in_at;
 
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomElasticIn::default().tween(value_delta, percent)
+    }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomElasticIn::default().derivative(value_delta, percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
+    }
+);
+
+declare_tween!(
+    /// An elastic tween out. Go [here](https://easings.net/#easeOutElastic) for a visual demonstration.
+    pub struct ElasticOut;
+
+    /// Creates a new [ElasticOut] Tweener.
+    pub fn elastic_out;
+
+    /// Creates a new [ElasticOut] Tweener at the given time.
+    pub fn elastic_out_at;
+
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomElasticOut::default().tween(value_delta, percent)
+    }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomElasticOut::default().derivative(value_delta, percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
+    }
+);
+
+declare_tween!(
+    /// An elastic tween in and out. Go [here](https://easings.net/#easeInOutElastic) for a visual demonstration.
+    pub struct ElasticInOut;
+
+    /// Creates a new [ElasticInOut] Tweener.
+    pub fn elastic_in_out;
+
+    /// Creates a new [ElasticInOut] Tweener at the given time.
+    pub fn elastic_in_out_at;
+
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomElasticInOut::default().tween(value_delta, percent)
+    }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        CustomElasticInOut::default().derivative(value_delta, percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::solve(|percent| self.tween(value_delta, percent), value)
+    }
+);
+
+/// An [ElasticIn] tween with an adjustable amplitude and period.
+///
+/// ```
+/// # use tween::{CustomElasticIn, ElasticIn, Tween};
+/// assert_eq!(
+///     CustomElasticIn::default().tween(10.0, 0.7),
+///     ElasticIn.tween(10.0, 0.7)
+/// );
+///
+/// // bigger, slower wobbles
+/// const WOBBLY: CustomElasticIn = CustomElasticIn::new(1.5, 0.5);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomElasticIn {
+    /// How far the tween swings, as a multiple of the distance it travels. Amplitudes below `1.0`
+    /// are treated as `1.0`.
+    pub amplitude: f32,
+
+    /// The length of each oscillation, as a fraction of the tween.
+    pub period: f32,
+}
+
+/// An [ElasticOut] tween with an adjustable amplitude and period.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomElasticOut {
+    /// How far the tween swings, as a multiple of the distance it travels. Amplitudes below `1.0`
+    /// are treated as `1.0`.
+    pub amplitude: f32,
+
+    /// The length of each oscillation, as a fraction of the tween.
+    pub period: f32,
+}
+
+/// An [ElasticInOut] tween with an adjustable amplitude and period.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct CustomElasticInOut {
+    /// How far the tween swings, as a multiple of the distance it travels. Amplitudes below `1.0`
+    /// are treated as `1.0`.
+    pub amplitude: f32,
+
+    /// The length of each oscillation, as a fraction of each half of the tween.
+    pub period: f32,
+}
+
+impl CustomElasticIn {
+    /// The amplitude and period used by [ElasticIn].
+    pub const DEFAULT: Self = Self::new(1.0, PERIOD);
+
+    /// Run the given Tween with a new time.
+    #[inline]
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, mut percent: f32) -> Value {
         if percent == 0.0 {
             return value_delta.scale(0.0);
@@ -26,36 +141,34 @@ declare_tween!(
 
         percent -= 1.0;
 
-        #[cfg(feature = "libm")]
-        let scalar = libm::powf(2.0, percent * 10.0);
-
-        #[cfg(feature = "std")]
-        let scalar = 2f32.powf(percent * 10.0);
+        let (amplitude, shift) = amplitude_shift(self.amplitude, self.period);
+        let post_fix = value_delta.scale(amplitude * decay(percent));
 
-        let post_fix = value_delta.scale(scalar);
-        let temp = (percent - SIGMA) * TAU * THREE_DOT_THREE_REPEATING;
+        post_fix.scale(-math::sin((percent - shift) * TAU * (1.0 / self.period)))
+    }
 
-        #[cfg(feature = "libm")]
-        let scalar = -libm::sinf(temp);
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let percent = percent - 1.0;
 
-        #[cfg(feature = "std")]
-        let scalar = -temp.sin();
+        let (amplitude, shift) = amplitude_shift(self.amplitude, self.period);
+        let frequency = TAU * (1.0 / self.period);
+        let phase = (percent - shift) * frequency;
+        let slope = DECAY_RATE * math::sin(phase) + frequency * math::cos(phase);
 
-        post_fix.scale(scalar)
+        value_delta.scale(-amplitude * decay(percent) * slope)
     }
-);
-
-declare_tween!(
-    /// An elastic tween out. Go [here](https://easings.net/#easeOutElastic) for a visual demonstration.
-    pub struct ElasticOut;
+}
 
-    /// Creates a new [ElasticOut] Tweener.
-    pub fn elastic_out;
-
-    /// Creates a new [ElasticOut] Tweener at the given time.
-    pub fn elastic_out_at;
+impl CustomElasticOut {
+    /// The amplitude and period used by [ElasticOut].
+    pub const DEFAULT: Self = Self::new(1.0, PERIOD);
 
-    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value,  percent: f32) -> Value {
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
         if percent == 0.0 {
             return value_delta.scale(0.0);
         }
@@ -64,28 +177,32 @@ declare_tween!(
             return value_delta;
         }
 
-        let temp = (percent - SIGMA) * TAU * THREE_DOT_THREE_REPEATING;
-
-        #[cfg(feature = "libm")]
-        let scalar = libm::powf(2.0, -10.0 * percent) * libm::sinf(temp);
-
-        #[cfg(feature = "std")]
-        let scalar = 2f32.powf(-10.0 * percent) * temp.sin();
+        let (amplitude, shift) = amplitude_shift(self.amplitude, self.period);
+        let temp = (percent - shift) * TAU * (1.0 / self.period);
+        let scalar = amplitude * decay(-percent) * math::sin(temp);
 
         value_delta.scale(scalar) + value_delta
     }
-);
 
-declare_tween!(
-    /// An elastic tween in and out. Go [here](https://easings.net/#easeInOutElastic) for a visual demonstration.
-    pub struct ElasticInOut;
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let (amplitude, shift) = amplitude_shift(self.amplitude, self.period);
+        let frequency = TAU * (1.0 / self.period);
+        let phase = (percent - shift) * frequency;
+        let slope = -DECAY_RATE * math::sin(phase) + frequency * math::cos(phase);
 
-    /// Creates a new [ElasticInOut] Tweener.
-    pub fn elastic_in_out;
+        value_delta.scale(amplitude * decay(-percent) * slope)
+    }
+}
 
-    /// Creates a new [ElasticInOut] Tweener at the given time.
-    pub fn elastic_in_out_at;
+impl CustomElasticInOut {
+    /// The amplitude and period used by [ElasticInOut].
+    pub const DEFAULT: Self = Self::new(1.0, PERIOD_IN_OUT);
 
+    /// Run the given Tween with a new time.
+    #[inline]
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, mut percent: f32) -> Value {
         if percent == 0.0 {
             return value_delta.scale(0.0);
@@ -97,43 +214,193 @@ declare_tween!(
 
         percent = (percent * 2.0) - 1.0;
 
-        if percent < 0.0 {
-            #[cfg(feature = "libm")]
-            let scalar = libm::powf(2.0, percent * 10.0);
-
-            #[cfg(feature = "std")]
-            let scalar = 2f32.powf(percent * 10.0);
-
-            let post_fix = value_delta.scale(scalar);
-            let temp = (percent - SIGMA_IN_OUT) * TAU * FORTY_FIVE;
-
-            #[cfg(feature = "libm")]
-            let temp_sin = libm::sinf(temp);
+        let (amplitude, shift) = if self.amplitude <= 1.0 && self.period == PERIOD_IN_OUT {
+            (1.0, SHIFT_IN_OUT)
+        } else {
+            amplitude_shift(self.amplitude, self.period)
+        };
+        let temp_sin = math::sin((percent - shift) * TAU * (1.0 / self.period));
 
-            #[cfg(feature = "std")]
-            let temp_sin = temp.sin();
+        if percent < 0.0 {
+            let post_fix = value_delta.scale(amplitude * decay(percent));
 
             post_fix.scale(-0.5 * temp_sin)
         } else {
-            #[cfg(feature = "libm")]
-            let scalar = libm::powf(2.0, percent * -10.0);
+            let post_fix = value_delta.scale(amplitude * decay(-percent));
 
-            #[cfg(feature = "std")]
-            let scalar = 2f32.powf(-10.0 * percent);
+            post_fix.scale(temp_sin * 0.5) + value_delta
+        }
+    }
 
-            let post_fix = value_delta.scale(scalar);
-            let temp = (percent - SIGMA_IN_OUT) * TAU * FORTY_FIVE;
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let percent = (percent * 2.0) - 1.0;
 
-            #[cfg(feature = "libm")]
-            let temp_sin = libm::sinf(temp);
+        let (amplitude, shift) = if self.amplitude <= 1.0 && self.period == PERIOD_IN_OUT {
+            (1.0, SHIFT_IN_OUT)
+        } else {
+            amplitude_shift(self.amplitude, self.period)
+        };
+        let frequency = TAU * (1.0 / self.period);
+        let phase = (percent - shift) * frequency;
+
+        // each half is squashed into half the time, which cancels out halving its height
+        let slope = if percent < 0.0 {
+            -amplitude * decay(percent) * (DECAY_RATE * math::sin(phase) + frequency * math::cos(phase))
+        } else {
+            amplitude * decay(-percent) * (-DECAY_RATE * math::sin(phase) + frequency * math::cos(phase))
+        };
 
-            #[cfg(feature = "std")]
-            let temp_sin = temp.sin();
+        value_delta.scale(slope)
+    }
+}
+
+/// How fast [decay] grows, which is `10 * ln(2)`.
+const DECAY_RATE: f32 = 10.0 * LN_2;
+
+/// The exponential decay of the elastic tweens, which is `2^(10 * t)`.
+#[inline(always)]
+fn decay(t: f32) -> f32 {
+    #[cfg(feature = "libm")]
+    let output = libm::powf(2.0, t * 10.0);
+
+    #[cfg(feature = "std")]
+    let output = 2f32.powf(t * 10.0);
+
+    output
+}
+
+/// Returns the effective amplitude, and how far the sine wave must be shifted so that the curve
+/// still starts at `0.0` and ends at `1.0`.
+#[inline(always)]
+fn amplitude_shift(amplitude: f32, period: f32) -> (f32, f32) {
+    if amplitude <= 1.0 {
+        (1.0, period / 4.0)
+    } else {
+        (amplitude, period / TAU * math::asin(1.0 / amplitude))
+    }
+}
+
+macro_rules! impl_custom_elastic {
+    ($($name:ident),*) => {
+        $(
+            impl $name {
+                /// Creates a new tween with the given amplitude and period.
+                pub const fn new(amplitude: f32, period: f32) -> Self {
+                    Self { amplitude, period }
+                }
+            }
+
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::DEFAULT
+                }
+            }
+
+            impl<Value> crate::Tween<Value> for $name
+            where
+                Value: crate::TweenValue,
+            {
+                #[inline]
+                fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+                    self.tween(value_delta, percent)
+                }
+
+                #[inline]
+                fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+                    self.derivative(value_delta, percent)
+                }
+            }
+        )*
+    };
+}
+
+impl_custom_elastic!(CustomElasticIn, CustomElasticOut, CustomElasticInOut);
 
-            post_fix.scale(temp_sin * 0.5) + value_delta
+test_tween!(Elastic);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tween;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn custom_defaults() {
+        // golden values from the old closed form Elastic formulas, which had an amplitude of `1.0` and
+        // period of `0.3`
+        const PERCENTS: [f32; 9] = [0.0, 0.1, 0.25, 0.4, 0.5, 0.6, 0.75, 0.9, 1.0];
+        const IN: [f32; 9] = [
+            0.0,
+            0.001953125,
+            -0.0055242716,
+            0.015625,
+            -0.015625013,
+            -0.031249948,
+            0.08838829,
+            -0.25000024,
+            1.0,
+        ];
+        const OUT: [f32; 9] = [
+            0.0, 1.25, 0.9116117, 1.03125, 1.015625, 0.984375, 1.0055243, 0.9980469, 1.0,
+        ];
+        const IN_OUT: [f32; 9] = [
+            0.0,
+            0.0003391572,
+            0.0119694425,
+            -0.1174616,
+            0.5,
+            1.1174616,
+            0.98803055,
+            0.99966085,
+            1.0,
+        ];
+
+        fn check(mut tween: impl Tween<f32>, expected: [f32; 9]) {
+            for (percent, expected) in PERCENTS.into_iter().zip(expected) {
+                assert_relative_eq!(
+                    tween.tween(1.0, percent),
+                    expected,
+                    max_relative = 0.0001,
+                    epsilon = 0.00001
+                );
+            }
         }
 
+        check(CustomElasticIn::default(), IN);
+        check(CustomElasticOut::default(), OUT);
+        check(CustomElasticInOut::default(), IN_OUT);
+        check(ElasticIn, IN);
+        check(ElasticOut, OUT);
+        check(ElasticInOut, IN_OUT);
     }
-);
 
-test_tween!(Elastic);
+    #[test]
+    fn custom_amplitude_period() {
+        const TUNED: CustomElasticOut = CustomElasticOut::new(2.0, 0.5);
+        let mut tuned = TUNED;
+
+        // still starts and ends in the right place, with no jump at either end
+        assert_eq!(tuned.tween(1.0, 0.0), 0.0);
+        assert_eq!(tuned.tween(1.0, 1.0), 1.0);
+        assert_relative_eq!(tuned.tween(1.0, 0.0001), 0.0, epsilon = 0.01);
+        assert_relative_eq!(tuned.tween(1.0, 0.9999), 1.0, epsilon = 0.01);
+
+        let peak = |mut tween: CustomElasticOut| {
+            (0..=100)
+                .map(|i| tween.tween(1.0, i as f32 / 100.0))
+                .fold(0.0, f32::max)
+        };
+        assert!(peak(TUNED) > peak(CustomElasticOut::DEFAULT));
+
+        let mut tuned = CustomElasticInOut::new(1.5, 0.6);
+        assert_relative_eq!(tuned.tween(1.0, 0.5), 0.5, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn custom_derivative() {
+        crate::tweens::assert_derivative_matches(CustomElasticOut::new(2.0, 0.5));
+    }
+}
@@ -1,3 +1,6 @@
+use crate::math;
+use core::f32::consts::LN_2;
+
 declare_tween!(
     /// An exponenential tween in. See [here](https://easings.net/#easeInExpo)
     pub struct ExpoIn;
@@ -24,6 +27,14 @@ declare_tween!(
 
         value_delta.scale(scalar)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(10.0 * LN_2 * math::powf(2.0, 10.0 * (percent - 1.0)))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| 1.0 + math::log2(progress) / 10.0)
+    }
 );
 
 declare_tween!(
@@ -49,6 +60,14 @@ declare_tween!(
             value_delta.scale(1.0 - powf)
         }
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(10.0 * LN_2 * math::powf(2.0, -10.0 * percent))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| -math::log2(1.0 - progress) / 10.0)
+    }
 );
 
 declare_tween!(
@@ -94,6 +113,23 @@ declare_tween!(
 
         value_delta.scale(powf)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let percent = percent * 2.0;
+
+        let exponent = if percent < 1.0 { 10.0 * (percent - 1.0) } else { -10.0 * (percent - 1.0) };
+        value_delta.scale(10.0 * LN_2 * math::powf(2.0, exponent))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| {
+            if progress < 0.5 {
+                (1.0 + math::log2(progress * 2.0) / 10.0) / 2.0
+            } else {
+                (1.0 - math::log2(2.0 - progress * 2.0) / 10.0) / 2.0
+            }
+        })
+    }
 );
 
 test_tween!(Expo);
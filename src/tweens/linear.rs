@@ -11,6 +11,14 @@ declare_tween!(
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
         value_delta.scale(percent)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, _percent: f32) -> Value {
+        value_delta
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| progress)
+    }
 );
 
 #[cfg(test)]
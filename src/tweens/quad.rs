@@ -11,6 +11,14 @@ declare_tween!(
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
         value_delta.scale(percent * percent)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(2.0 * percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_in(progress, 2.0))
+    }
 );
 
 declare_tween!(
@@ -26,6 +34,14 @@ declare_tween!(
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
         value_delta.scale(-percent).scale(percent - 2.0)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(2.0 - 2.0 * percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_out(progress, 2.0))
+    }
 );
 
 declare_tween!(
@@ -50,6 +66,17 @@ declare_tween!(
         };
         value_delta.scale(scalar / 2.0)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let percent = percent * 2.0;
+
+        let slope = if percent < 1.0 { 2.0 * percent } else { 2.0 * (2.0 - percent) };
+        value_delta.scale(slope)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_in_out(progress, 2.0))
+    }
 );
 
 test_tween!(Quad);
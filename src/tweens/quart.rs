@@ -11,6 +11,14 @@ declare_tween!(
     pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
         value_delta.scale(percent * percent * percent * percent)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(4.0 * percent * percent * percent)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_in(progress, 4.0))
+    }
 );
 
 declare_tween!(
@@ -27,6 +35,15 @@ declare_tween!(
         percent -= 1.0;
         value_delta.scale(-(percent * percent * percent * percent - 1.0))
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let t = percent - 1.0;
+        value_delta.scale(-4.0 * t * t * t)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_out(progress, 4.0))
+    }
 );
 
 declare_tween!(
@@ -50,6 +67,23 @@ declare_tween!(
         };
         value_delta.scale(scalar / 2.0)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let percent = percent * 2.0;
+
+        let slope = if percent < 1.0 {
+            4.0 * percent * percent * percent
+        } else {
+            let p = percent - 2.0;
+
+            -4.0 * p * p * p
+        };
+        value_delta.scale(slope)
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| crate::inverse::power_in_out(progress, 4.0))
+    }
 );
 
 test_tween!(Quart);
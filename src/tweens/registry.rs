@@ -0,0 +1,215 @@
+use super::easing::normalize;
+use crate::{Easing, ParseEasingError, Tween, TweenValue};
+use alloc::{collections::BTreeMap, string::String, sync::Arc};
+
+/// A list of named easings, which adds your own easings to the built-in [Easing]s so they can be
+/// chosen by name too, such as by mods or scripts.
+///
+/// An easing is registered as a function from the percent of time, `0.0` to `1.0`, to how far along
+/// the tween is, which is usually `0.0` to `1.0` as well. Names follow the same rules as
+/// [Easing]'s, ignoring case, `_`, `-` and spaces.
+///
+/// ```
+/// # use tween::{Easing, EasingRegistry, RegisteredEasing, Tweener};
+/// let mut registry = EasingRegistry::new();
+/// registry.register("smoothstep", |t| t * t * (3.0 - 2.0 * t));
+///
+/// let mut tweener = Tweener::new(0.0f32, 10.0, 1.0, registry.get("Smoothstep").unwrap());
+/// assert_eq!(tweener.move_to(0.25), 1.5625);
+///
+/// // the built-in easings are always there
+/// assert_eq!(
+///     registry.get("easeInQuad"),
+///     Ok(RegisteredEasing::BuiltIn(Easing::QuadIn))
+/// );
+/// ```
+///
+/// A registered easing with the same name as a built-in one replaces it. Registries are cheap to
+/// clone, since they share the easings themselves.
+#[derive(Clone, Default)]
+pub struct EasingRegistry {
+    easings: BTreeMap<String, CustomEasing>,
+}
+
+impl EasingRegistry {
+    /// Creates a new registry, with only the built-in easings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an easing with the given name, returning the easing it replaced, if any.
+    pub fn register(
+        &mut self,
+        name: &str,
+        easing: impl Fn(f32) -> f32 + Send + Sync + 'static,
+    ) -> Option<CustomEasing> {
+        self.easings
+            .insert(normalize(name).collect(), CustomEasing::new(name, easing))
+    }
+
+    /// Removes the easing with the given name, returning it. Built-in easings can't be removed.
+    pub fn unregister(&mut self, name: &str) -> Option<CustomEasing> {
+        self.easings.remove(&normalize(name).collect::<String>())
+    }
+
+    /// Returns the easing with the given name, checking the registered easings before the
+    /// built-in ones.
+    pub fn get(&self, name: &str) -> Result<RegisteredEasing, ParseEasingError> {
+        match self.easings.get(&normalize(name).collect::<String>()) {
+            Some(easing) => Ok(RegisteredEasing::Custom(easing.clone())),
+            None => name.parse().map(RegisteredEasing::BuiltIn),
+        }
+    }
+
+    /// Returns an iterator over the names of the registered easings, as they were registered.
+    /// This doesn't include the built-in easings, which are in [Easing::iter].
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.easings.values().map(CustomEasing::name)
+    }
+}
+
+impl core::fmt::Debug for EasingRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+/// An easing added to an [EasingRegistry].
+#[derive(Clone)]
+pub struct CustomEasing {
+    name: Arc<str>,
+    easing: Arc<dyn Fn(f32) -> f32 + Send + Sync>,
+}
+
+impl CustomEasing {
+    /// Creates a new easing with the given name, which maps the percent of time to how far along
+    /// the tween is.
+    pub fn new(name: &str, easing: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        Self {
+            name: name.into(),
+            easing: Arc::new(easing),
+        }
+    }
+
+    /// The name the easing was created with.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns how far along the tween is at the given percent of time.
+    pub fn ease(&self, percent: f32) -> f32 {
+        (self.easing)(percent)
+    }
+}
+
+impl core::fmt::Debug for CustomEasing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CustomEasing").field(&self.name).finish()
+    }
+}
+
+/// Two custom easings are the same if they share the same function, such as when one is a clone
+/// of the other.
+impl PartialEq for CustomEasing {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.easing, &other.easing)
+    }
+}
+
+impl<Value> Tween<Value> for CustomEasing
+where
+    Value: TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(self.ease(percent))
+    }
+}
+
+/// An easing returned from an [EasingRegistry], which is either one of the built-in [Easing]s or
+/// a [CustomEasing].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegisteredEasing {
+    /// A built-in easing.
+    BuiltIn(Easing),
+
+    /// An easing added to the registry.
+    Custom(CustomEasing),
+}
+
+impl RegisteredEasing {
+    /// The name of the easing. For built-in easings, this is [Easing::name].
+    pub fn name(&self) -> &str {
+        match self {
+            Self::BuiltIn(easing) => easing.name(),
+            Self::Custom(easing) => easing.name(),
+        }
+    }
+}
+
+impl<Value> Tween<Value> for RegisteredEasing
+where
+    Value: TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        match self {
+            Self::BuiltIn(easing) => easing.tween(value_delta, percent),
+            Self::Custom(easing) => easing.tween(value_delta, percent),
+        }
+    }
+
+    #[inline]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        match self {
+            Self::BuiltIn(easing) => easing.derivative(value_delta, percent),
+            Self::Custom(easing) => easing.derivative(value_delta, percent),
+        }
+    }
+
+    #[inline]
+    fn inverse(&mut self, value_delta: Value, value: Value) -> Option<f32>
+    where
+        Value: PartialOrd,
+    {
+        match self {
+            Self::BuiltIn(easing) => easing.inverse(value_delta, value),
+            Self::Custom(easing) => easing.inverse(value_delta, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn registry() {
+        let mut registry = EasingRegistry::new();
+        assert!(
+            registry
+                .register("Snap In", |t| if t < 0.5 { 0.0 } else { 1.0 })
+                .is_none()
+        );
+        let mut easing = registry.get("snap_in").unwrap();
+        assert_eq!(easing.name(), "Snap In");
+        assert_eq!(easing.tween(10.0f32, 0.75), 10.0);
+        assert_eq!(easing.inverse(10.0f32, 5.0), Some(0.5));
+
+        // registered easings come before the built-in ones...
+        let replaced = registry.register("linear", |t| t * 2.0);
+        assert!(replaced.is_none());
+        assert_eq!(registry.get("Linear").unwrap().tween(1.0f32, 0.25), 0.5);
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["linear", "Snap In"]);
+
+        // ...until they're removed
+        assert!(registry.unregister("LINEAR").is_some());
+        assert_eq!(registry.get("linear"), Ok(RegisteredEasing::BuiltIn(Easing::Linear)));
+        assert_eq!(registry.get("nope"), Err(ParseEasingError));
+
+        // clones share their easings
+        let clone = registry.clone();
+        assert_eq!(clone.get("snap in"), registry.get("snapIn"));
+    }
+}
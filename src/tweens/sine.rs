@@ -1,3 +1,4 @@
+use crate::math;
 use core::f32::consts::PI;
 
 declare_tween!(
@@ -19,6 +20,14 @@ declare_tween!(
 
         value_delta.scale(-time) + value_delta
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(PI / 2.0 * math::sin(percent * PI / 2.0))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| math::acos(1.0 - progress) * 2.0 / PI)
+    }
 );
 
 declare_tween!(
@@ -40,6 +49,14 @@ declare_tween!(
 
         value_delta.scale(time)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(PI / 2.0 * math::cos(percent * PI / 2.0))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| math::asin(progress) * 2.0 / PI)
+    }
 );
 
 declare_tween!(
@@ -61,6 +78,14 @@ declare_tween!(
         let time = (percent * PI).cos() - 1.0;
         value_delta.scale(-time / 2.0)
     }
+
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        value_delta.scale(PI / 2.0 * math::sin(percent * PI))
+    }
+
+    pub fn inverse<Value: crate::TweenValue + PartialOrd>(&mut self, value_delta: Value, value: Value) -> Option<f32> {
+        crate::inverse::invert(self, value_delta, value, |progress| math::acos(1.0 - 2.0 * progress) / PI)
+    }
 );
 
 test_tween!(Sine);
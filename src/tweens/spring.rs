@@ -0,0 +1,563 @@
+use crate::math;
+use core::f32::consts::{PI, TAU};
+
+/// How close a damping ratio must be to `1.0` to be treated as critically damped.
+const CRITICAL_EPSILON: f32 = 1e-4;
+
+/// How many times we sample a spring when looking for its settle duration, at least.
+const SETTLE_SAMPLES: usize = 1024;
+
+/// The most times we will sample a spring, which only lightly damped springs reach.
+const MAX_SETTLE_SAMPLES: usize = 1 << 16;
+
+/// The longest a spring's settle duration can be, in seconds, so that springs which never come to
+/// rest still finish.
+const MAX_SETTLE_DURATION: f32 = 60.0;
+
+/// A physically based spring tween, which simulates a mass on a spring pulling it to the end
+/// value.
+///
+/// Unlike the other tweens in this library, a spring has a natural duration: the time it takes to
+/// come to rest. A `Spring` calculates this duration (in seconds) when it's created, as its
+/// [settle_duration](Self::settle_duration), and the easiest way to use it is with
+/// [Tweener::spring](crate::Tweener::spring), which uses that duration for the Tweener. The
+/// Tweener will only be finished once the spring has settled within its
+/// [tolerance](Self::tolerance) of the end value.
+///
+/// ```
+/// # use tween::{Spring, Tweener};
+/// // a quick spring with a little bounce
+/// let spring = Spring::from_duration_bounce(0.4, 0.3);
+/// let mut tweener = Tweener::spring(0.0, 100.0, spring);
+///
+/// // it overshoots the end, and then comes back
+/// assert!((0..100).any(|_| tweener.move_by(1.0 / 60.0) > 100.0));
+/// while !tweener.is_finished() {
+///     tweener.move_by(1.0 / 60.0);
+/// }
+/// assert_eq!(tweener.move_by(0.0), 100.0);
+/// ```
+///
+/// ## Regimes
+///
+/// How bouncy a spring is depends on its [damping_ratio](Self::damping_ratio):
+///
+/// - below `1.0`, the spring is *underdamped*, and will overshoot and oscillate around the end.
+/// - at exactly `1.0`, the spring is *critically damped*, and will reach the end as quickly as it
+///   can without overshooting.
+/// - above `1.0`, the spring is *overdamped*, and will creep towards the end without overshooting.
+///
+/// A spring with no damping at all never comes to rest, so its settle duration is capped at a
+/// minute, after which it snaps to the end value. Very lightly damped springs are capped the same
+/// way.
+///
+/// ## Units
+///
+/// Time is measured in seconds, and distance is measured as a fraction of the distance between
+/// the start and end values, so an [initial_velocity](Self::initial_velocity) of `2.0` moves
+/// twice the whole distance per second, and a `tolerance` of `0.001` is a tenth of a percent of
+/// the distance.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub struct Spring {
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    initial_velocity: f32,
+    tolerance: f32,
+    settle_duration: f32,
+}
+
+impl Spring {
+    /// The default tolerance of a Spring, which is a tenth of a percent of the distance it
+    /// travels.
+    pub const DEFAULT_TOLERANCE: f32 = 0.001;
+
+    /// Creates a new Spring out of its physical properties, starting at rest.
+    ///
+    /// All three values should be greater than zero.
+    pub fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        let mut output = Self {
+            stiffness,
+            damping,
+            mass,
+            initial_velocity: 0.0,
+            tolerance: Self::DEFAULT_TOLERANCE,
+            settle_duration: 0.0,
+        };
+        output.settle_duration = output.calculate_settle_duration();
+
+        output
+    }
+
+    /// Creates a new Spring with a perceptual duration and bounce, in the same way as SwiftUI
+    /// and Framer.
+    ///
+    /// `duration` is roughly how long the spring takes to reach the end, in seconds, although it
+    /// will take longer than that to completely settle. `bounce` should be between `-1.0` and
+    /// `1.0`: `0.0` is critically damped, values above it are increasingly bouncy, and values
+    /// below it are increasingly overdamped. It's clamped to `-0.999..=1.0`, since a bounce of
+    /// `-1.0` would be infinitely damped.
+    pub fn from_duration_bounce(duration: f32, bounce: f32) -> Self {
+        let bounce = bounce.clamp(-0.999, 1.0);
+        let stiffness = (TAU / duration) * (TAU / duration);
+        let damping = if bounce >= 0.0 {
+            (1.0 - bounce) * 4.0 * PI / duration
+        } else {
+            4.0 * PI / (duration * (1.0 + bounce))
+        };
+
+        Self::new(stiffness, damping, 1.0)
+    }
+
+    /// Returns the Spring with the given initial velocity, in distances per second. Positive
+    /// velocities move towards the end value.
+    pub fn with_initial_velocity(mut self, initial_velocity: f32) -> Self {
+        self.initial_velocity = initial_velocity;
+        self.settle_duration = self.calculate_settle_duration();
+
+        self
+    }
+
+    /// Returns the Spring with the given tolerance, as a fraction of the distance it travels. The
+    /// spring is settled once it stays within this tolerance of the end value.
+    ///
+    /// The tolerance should be greater than zero and less than one.
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self.settle_duration = self.calculate_settle_duration();
+
+        self
+    }
+
+    /// The stiffness of the spring. Stiffer springs move faster.
+    pub fn stiffness(&self) -> f32 {
+        self.stiffness
+    }
+
+    /// The damping of the spring. More damped springs bounce less.
+    pub fn damping(&self) -> f32 {
+        self.damping
+    }
+
+    /// The mass on the end of the spring. Heavier masses move slower, and bounce more.
+    pub fn mass(&self) -> f32 {
+        self.mass
+    }
+
+    /// The initial velocity of the spring, in distances per second.
+    pub fn initial_velocity(&self) -> f32 {
+        self.initial_velocity
+    }
+
+    /// The tolerance of the spring, as a fraction of the distance it travels.
+    pub fn tolerance(&self) -> f32 {
+        self.tolerance
+    }
+
+    /// The time, in seconds, which the spring takes to settle within its tolerance of the end
+    /// value. This is never more than a minute, even for springs which never settle.
+    pub fn settle_duration(&self) -> f32 {
+        self.settle_duration
+    }
+
+    /// The damping ratio of the spring. See [Spring] for more information.
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * math::sqrt(self.stiffness * self.mass))
+    }
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        if percent <= 0.0 {
+            return value_delta.scale(0.0);
+        }
+
+        if percent >= 1.0 {
+            return value_delta;
+        }
+
+        let scalar = 1.0 + self.motion().displacement(percent * self.settle_duration);
+
+        value_delta.scale(scalar)
+    }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        if percent <= 0.0 || percent >= 1.0 {
+            return value_delta.scale(0.0);
+        }
+
+        // the motion runs in seconds, so we stretch its velocity out over the whole tween
+        let velocity = self.motion().velocity(percent * self.settle_duration);
+
+        value_delta.scale(velocity * self.settle_duration)
+    }
+
+    fn motion(&self) -> Motion {
+        // we start one whole distance away from the end
+        let x0 = -1.0;
+        let v0 = self.initial_velocity;
+
+        let natural_frequency = math::sqrt(self.stiffness / self.mass);
+        let ratio = self.damping_ratio();
+
+        // floats rarely land on exactly `1.0`, and the underdamped solution is unstable as it
+        // gets close to it, so we snap nearby ratios to critically damped
+        if (ratio - 1.0).abs() < CRITICAL_EPSILON {
+            Motion::CriticallyDamped {
+                decay: natural_frequency,
+                constant: x0,
+                linear: v0 + natural_frequency * x0,
+            }
+        } else if ratio < 1.0 {
+            let decay = ratio * natural_frequency;
+            let frequency = natural_frequency * math::sqrt(1.0 - ratio * ratio);
+
+            Motion::Underdamped {
+                decay,
+                frequency,
+                cos: x0,
+                sin: (v0 + decay * x0) / frequency,
+            }
+        } else {
+            let spread = natural_frequency * math::sqrt(ratio * ratio - 1.0);
+            let slow = -ratio * natural_frequency + spread;
+            let fast = -ratio * natural_frequency - spread;
+
+            let fast_amount = (v0 - slow * x0) / (fast - slow);
+
+            Motion::Overdamped {
+                slow,
+                fast,
+                slow_amount: x0 - fast_amount,
+                fast_amount,
+            }
+        }
+    }
+
+    fn calculate_settle_duration(&self) -> f32 {
+        let motion = self.motion();
+        let decay = motion.decay();
+        if decay <= 0.0 || decay.is_nan() {
+            return MAX_SETTLE_DURATION;
+        }
+
+        // first, find a time where the spring can't possibly be outside of the tolerance...
+        let mut end = 1.0 / decay;
+        for _ in 0..64 {
+            if motion.bound(end) < self.tolerance {
+                break;
+            }
+            end *= 2.0;
+        }
+
+        // ...then look backwards from there for the last time it was outside of it, sampling
+        // often enough to catch every peak of an oscillation...
+        let samples = match motion {
+            Motion::Underdamped { frequency, .. } => (end * frequency / TAU * 16.0) as usize,
+            _ => 0,
+        }
+        .clamp(SETTLE_SAMPLES, MAX_SETTLE_SAMPLES);
+
+        let step = end / samples as f32;
+        let Some(last) = (0..=samples)
+            .rev()
+            .find(|&i| motion.displacement(i as f32 * step).abs() >= self.tolerance)
+        else {
+            return 0.0;
+        };
+
+        // ...and then bisect to find exactly where it entered the tolerance.
+        let (mut low, mut high) = (last as f32 * step, (last + 1) as f32 * step);
+        for _ in 0..24 {
+            let middle = (low + high) / 2.0;
+            if motion.displacement(middle).abs() >= self.tolerance {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        high.min(MAX_SETTLE_DURATION)
+    }
+}
+
+impl Default for Spring {
+    /// A critically damped spring, which takes about half a second.
+    fn default() -> Self {
+        Self::from_duration_bounce(0.5, 0.0)
+    }
+}
+
+/// The closed form solution to a spring's motion, as its displacement from the end value.
+#[derive(Debug, Clone, Copy)]
+enum Motion {
+    Underdamped {
+        decay: f32,
+        frequency: f32,
+        cos: f32,
+        sin: f32,
+    },
+    CriticallyDamped {
+        decay: f32,
+        constant: f32,
+        linear: f32,
+    },
+    Overdamped {
+        slow: f32,
+        fast: f32,
+        slow_amount: f32,
+        fast_amount: f32,
+    },
+}
+
+impl Motion {
+    fn displacement(&self, time: f32) -> f32 {
+        match *self {
+            Motion::Underdamped {
+                decay,
+                frequency,
+                cos,
+                sin,
+            } => math::exp(-decay * time) * (cos * math::cos(frequency * time) + sin * math::sin(frequency * time)),
+            Motion::CriticallyDamped {
+                decay,
+                constant,
+                linear,
+            } => math::exp(-decay * time) * (constant + linear * time),
+            Motion::Overdamped {
+                slow,
+                fast,
+                slow_amount,
+                fast_amount,
+            } => slow_amount * math::exp(slow * time) + fast_amount * math::exp(fast * time),
+        }
+    }
+
+    /// The rate of change of [displacement](Self::displacement) at `time`.
+    fn velocity(&self, time: f32) -> f32 {
+        match *self {
+            Motion::Underdamped {
+                decay,
+                frequency,
+                cos,
+                sin,
+            } => {
+                let cos_rate = sin * frequency - decay * cos;
+                let sin_rate = -cos * frequency - decay * sin;
+
+                math::exp(-decay * time)
+                    * (cos_rate * math::cos(frequency * time) + sin_rate * math::sin(frequency * time))
+            }
+            Motion::CriticallyDamped {
+                decay,
+                constant,
+                linear,
+            } => math::exp(-decay * time) * (linear - decay * (constant + linear * time)),
+            Motion::Overdamped {
+                slow,
+                fast,
+                slow_amount,
+                fast_amount,
+            } => slow_amount * slow * math::exp(slow * time) + fast_amount * fast * math::exp(fast * time),
+        }
+    }
+
+    /// The slowest rate at which the motion decays.
+    fn decay(&self) -> f32 {
+        match *self {
+            Motion::Underdamped { decay, .. } | Motion::CriticallyDamped { decay, .. } => decay,
+            Motion::Overdamped { slow, .. } => -slow,
+        }
+    }
+
+    /// An upper bound on the displacement at `time`.
+    fn bound(&self, time: f32) -> f32 {
+        let amount = match *self {
+            Motion::Underdamped { cos, sin, .. } => math::sqrt(cos * cos + sin * sin),
+            Motion::CriticallyDamped { constant, linear, .. } => constant.abs() + linear.abs() * time,
+            Motion::Overdamped {
+                slow_amount,
+                fast_amount,
+                ..
+            } => slow_amount.abs() + fast_amount.abs(),
+        };
+
+        amount * math::exp(-self.decay() * time)
+    }
+}
+
+impl<Value> crate::Tween<Value> for Spring
+where
+    Value: crate::TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+
+    #[inline]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.derivative(value_delta, percent)
+    }
+}
+
+impl<Value> crate::Tweener<Value, f32, Spring>
+where
+    Value: crate::TweenValue,
+{
+    /// Creates a new [Spring] Tweener, which lasts for the spring's
+    /// [settle_duration](Spring::settle_duration).
+    ///
+    /// For other time types, use [Tweener::new](crate::Tweener::new) with the spring's
+    /// `settle_duration`.
+    pub fn spring(start: Value, end: Value, spring: Spring) -> Self {
+        Self::new(start, end, spring.settle_duration(), spring)
+    }
+
+    /// Creates a new [Spring] Tweener at the given time, which lasts for the spring's
+    /// [settle_duration](Spring::settle_duration).
+    pub fn spring_at(start: Value, end: Value, spring: Spring, current_time: f32) -> Self {
+        Self::new_at(start, end, spring.settle_duration(), spring, current_time)
+    }
+}
+
+impl<Value> crate::FixedTweener<Value, f32, Spring>
+where
+    Value: crate::TweenValue,
+{
+    /// Creates a new [Spring] Tweener, which lasts for the spring's
+    /// [settle_duration](Spring::settle_duration).
+    pub fn spring(start: Value, end: Value, spring: Spring, delta: f32) -> Self {
+        Self::new(start, end, spring.settle_duration(), spring, delta)
+    }
+
+    /// Creates a new [Spring] Tweener at the given time, which lasts for the spring's
+    /// [settle_duration](Spring::settle_duration).
+    pub fn spring_at(start: Value, end: Value, spring: Spring, current_time: f32, delta: f32) -> Self {
+        Self::new_at(start, end, spring.settle_duration(), spring, current_time, delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tweener;
+    use approx::assert_relative_eq;
+
+    /// Samples the spring's progress across its settle duration.
+    fn samples(mut spring: Spring) -> impl Iterator<Item = f32> {
+        (0..=1000).map(move |i| spring.tween(1.0, i as f32 / 1000.0))
+    }
+
+    #[test]
+    fn regimes() {
+        let critical = Spring::from_duration_bounce(0.5, 0.0);
+        assert_relative_eq!(critical.damping_ratio(), 1.0, epsilon = 0.0001);
+        assert!(samples(critical).all(|v| v <= 1.0));
+
+        let under = Spring::from_duration_bounce(0.5, 0.5);
+        assert!(under.damping_ratio() < 1.0);
+        assert!(samples(under).any(|v| v > 1.0));
+
+        let over = Spring::from_duration_bounce(0.5, -0.5);
+        assert!(over.damping_ratio() > 1.0);
+        assert!(samples(over).all(|v| v <= 1.0));
+        assert!(over.settle_duration() > critical.settle_duration());
+
+        // all three regimes are monotonic when they don't overshoot
+        for spring in [critical, over, Spring::new(100.0, 40.0, 1.0)] {
+            assert!(samples(spring).zip(samples(spring).skip(1)).all(|(a, b)| a <= b));
+        }
+    }
+
+    #[test]
+    fn settles() {
+        for spring in [
+            Spring::new(100.0, 10.0, 1.0),
+            Spring::new(100.0, 1.0, 1.0),
+            Spring::new(100.0, 20.0, 1.0),
+            Spring::new(100.0, 50.0, 1.0),
+            Spring::new(200.0, 10.0, 3.0).with_initial_velocity(-5.0),
+        ] {
+            let settle = spring.settle_duration();
+            let motion = spring.motion();
+
+            assert!(motion.displacement(settle * 0.999).abs() > spring.tolerance() * 0.99);
+            for i in 0..1000 {
+                let time = settle + i as f32 * 0.01;
+                assert!(motion.displacement(time).abs() <= spring.tolerance() * 1.01);
+            }
+        }
+
+        let loose = Spring::default().with_tolerance(0.1);
+        assert!(loose.settle_duration() < Spring::default().settle_duration());
+
+        // springs which never settle are capped
+        let undamped = Spring::new(100.0, 0.0, 1.0);
+        assert_eq!(undamped.settle_duration(), MAX_SETTLE_DURATION);
+        assert_eq!(Spring::new(100.0, 0.0001, 1.0).settle_duration(), MAX_SETTLE_DURATION);
+
+        let mut tweener = Tweener::spring(0.0, 10.0, undamped);
+        assert_ne!(tweener.move_by(0.1), 0.0);
+        assert_eq!(tweener.move_by(MAX_SETTLE_DURATION), 10.0);
+        assert!(tweener.is_finished());
+    }
+
+    #[test]
+    fn bounce_range() {
+        for bounce in [-1.0, -2.0, 1.0, 2.0] {
+            let spring = Spring::from_duration_bounce(0.5, bounce);
+            assert!(spring.damping().is_finite());
+            assert!(spring.settle_duration() > 0.0);
+            assert!(spring.settle_duration() <= MAX_SETTLE_DURATION);
+            assert!(samples(spring).all(f32::is_finite));
+        }
+
+        assert_eq!(
+            Spring::from_duration_bounce(0.5, 2.0),
+            Spring::from_duration_bounce(0.5, 1.0)
+        );
+        assert_eq!(
+            Spring::from_duration_bounce(0.5, -2.0),
+            Spring::from_duration_bounce(0.5, -1.0)
+        );
+        assert!(Spring::from_duration_bounce(0.5, -1.0).damping_ratio() > 1.0);
+    }
+
+    #[test]
+    fn initial_velocity() {
+        let still = Spring::default();
+        let pushed = still.with_initial_velocity(10.0);
+        let pulled = still.with_initial_velocity(-10.0);
+
+        let early = |mut spring: Spring| spring.tween(1.0, 0.01);
+        assert!(early(pushed) > early(still));
+        assert!(early(pulled) < 0.0);
+    }
+
+    #[test]
+    fn tweener() {
+        let spring = Spring::new(170.0, 26.0, 1.0);
+        let mut tweener = Tweener::spring(0.0, 10.0, spring);
+        assert_eq!(tweener.duration, spring.settle_duration());
+
+        tweener.move_to(spring.settle_duration() * 0.99);
+        assert!(!tweener.is_finished());
+        assert_eq!(tweener.move_to(spring.settle_duration()), 10.0);
+        assert!(tweener.is_finished());
+    }
+
+    #[test]
+    fn derivative() {
+        for spring in [
+            Spring::from_duration_bounce(0.5, 0.4),
+            Spring::from_duration_bounce(0.5, 0.0),
+            Spring::new(100.0, 40.0, 1.0),
+        ] {
+            crate::tweens::assert_derivative_matches(spring);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::math;
+
+/// A stepped tween, identical to CSS's `steps(count, jump)`.
+///
+/// Rather than moving smoothly, a `Steps` tween jumps between `count` evenly spaced values, which
+/// is useful for sprite animation frames or counters that should tick over.
+///
+/// ```
+/// # use tween::{StepPosition, Tweener};
+/// // four frames of a sprite sheet, over one second
+/// let mut tweener = Tweener::steps(0, 4, 1.0, 4, StepPosition::JumpEnd);
+///
+/// assert_eq!(tweener.move_to(0.0), 0);
+/// assert_eq!(tweener.move_to(0.3), 1);
+/// assert_eq!(tweener.move_to(0.99), 3);
+/// assert_eq!(tweener.move_to(1.0), 4);
+/// ```
+///
+/// With integer values, each step lands exactly on the value you'd expect from integer math, so
+/// frame indices never jitter by one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord)]
+pub struct Steps {
+    /// The number of steps, or intervals, this tween is divided into. A count of `0` is treated
+    /// as `1`.
+    pub count: u32,
+
+    /// Where in each interval the jumps happen.
+    pub jump: StepPosition,
+}
+
+/// Where the jumps of a [Steps] tween happen, matching CSS's `<step-position>`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord)]
+pub enum StepPosition {
+    /// The first jump happens right at the start, so the start value is never shown. This is
+    /// CSS's `jump-start`.
+    JumpStart,
+
+    /// The last jump happens right at the end, so the end value is only shown once the tween is
+    /// finished. This is CSS's `jump-end`, and is the default.
+    #[default]
+    JumpEnd,
+
+    /// There are no jumps at either end, so both the start and end values are held for a full
+    /// step. This is CSS's `jump-none`.
+    JumpNone,
+
+    /// There are jumps at both ends, so neither the start nor end values are held. This is CSS's
+    /// `jump-both`.
+    JumpBoth,
+}
+
+impl Steps {
+    /// Creates a new [Steps] tween.
+    pub const fn new(count: u32, jump: StepPosition) -> Self {
+        Self { count, jump }
+    }
+
+    /// Run the given Tween with a new time.
+    #[inline]
+    pub fn tween<Value: crate::TweenValue>(&mut self, value_delta: Value, percent: f32) -> Value {
+        let (step, jumps) = self.step(percent);
+
+        // we always land on the ends exactly...
+        if step == 0 {
+            return value_delta.scale(0.0);
+        }
+        if step == jumps {
+            return value_delta;
+        }
+
+        // ...and in between, we round the scale up by the tiniest amount. `k / n` can round
+        // below its true value as an `f32`, which would make an integer value truncate down to
+        // the wrong step, but the true value is never close enough to an integer for this nudge
+        // to push it over.
+        let scale = step as f32 / jumps as f32;
+
+        value_delta.scale(scale.next_up())
+    }
+
+    /// Returns how fast the Tween is changing at the given time. See
+    /// [Tween::derivative](crate::Tween::derivative).
+    ///
+    /// Steps only ever jump, so this is always zero.
+    #[inline]
+    pub fn derivative<Value: crate::TweenValue>(&mut self, value_delta: Value, _percent: f32) -> Value {
+        value_delta.scale(0.0)
+    }
+
+    /// Returns the current step and the total number of jumps.
+    fn step(&self, percent: f32) -> (i64, i64) {
+        let count = self.count.max(1) as i64;
+        let jumps = match self.jump {
+            StepPosition::JumpStart | StepPosition::JumpEnd => count,
+            StepPosition::JumpNone => (count - 1).max(1),
+            StepPosition::JumpBoth => count + 1,
+        };
+
+        let scaled = percent * count as f32;
+        let (floor, round) = (math::floor(scaled), math::round(scaled));
+
+        // a percent which should land exactly on a step (like `13.0 / 22.0`) can come out just
+        // short of it, so anything within rounding error of a step counts as reaching it
+        let mut step = if (scaled - round).abs() <= f32::EPSILON * count as f32 {
+            round as i64
+        } else {
+            floor as i64
+        };
+
+        if matches!(self.jump, StepPosition::JumpStart | StepPosition::JumpBoth) {
+            step += 1;
+        }
+
+        if (0.0..=1.0).contains(&percent) {
+            step = step.clamp(0, jumps);
+        }
+
+        (step, jumps)
+    }
+}
+
+impl<Value> crate::Tween<Value> for Steps
+where
+    Value: crate::TweenValue,
+{
+    #[inline]
+    fn tween(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.tween(value_delta, percent)
+    }
+
+    #[inline]
+    fn derivative(&mut self, value_delta: Value, percent: f32) -> Value {
+        self.derivative(value_delta, percent)
+    }
+}
+
+impl<Value, Time> crate::Tweener<Value, Time, Steps>
+where
+    Time: crate::TweenTime,
+    Value: crate::TweenValue,
+{
+    /// Creates a new [Steps] Tweener.
+    pub fn steps(start: Value, end: Value, duration: Time, count: u32, jump: StepPosition) -> Self {
+        Self::new(start, end, duration, Steps::new(count, jump))
+    }
+
+    /// Creates a new [Steps] Tweener at the given time.
+    pub fn steps_at(
+        start: Value,
+        end: Value,
+        duration: Time,
+        count: u32,
+        jump: StepPosition,
+        current_time: Time,
+    ) -> Self {
+        Self::new_at(start, end, duration, Steps::new(count, jump), current_time)
+    }
+}
+
+impl<Value, Time> crate::FixedTweener<Value, Time, Steps>
+where
+    Time: crate::TweenTime,
+    Value: crate::TweenValue,
+{
+    /// Creates a new [Steps] Tweener.
+    pub fn steps(start: Value, end: Value, duration: Time, count: u32, jump: StepPosition, delta: Time) -> Self {
+        Self::new(start, end, duration, Steps::new(count, jump), delta)
+    }
+
+    /// Creates a new [Steps] Tweener at the given time.
+    pub fn steps_at(
+        start: Value,
+        end: Value,
+        duration: Time,
+        count: u32,
+        jump: StepPosition,
+        current_time: Time,
+        delta: Time,
+    ) -> Self {
+        Self::new_at(start, end, duration, Steps::new(count, jump), current_time, delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTweener, Tweener};
+
+    fn sample(jump: StepPosition) -> [i32; 5] {
+        let mut steps = Steps::new(4, jump);
+        [0.0, 0.1, 0.3, 0.9, 1.0].map(|percent| steps.tween(100, percent))
+    }
+
+    #[test]
+    fn jump_modes() {
+        assert_eq!(sample(StepPosition::JumpStart), [25, 25, 50, 100, 100]);
+        assert_eq!(sample(StepPosition::JumpEnd), [0, 0, 25, 75, 100]);
+        assert_eq!(sample(StepPosition::JumpNone), [0, 0, 33, 100, 100]);
+        assert_eq!(sample(StepPosition::JumpBoth), [20, 20, 40, 80, 100]);
+    }
+
+    #[test]
+    fn exact_integers() {
+        for count in 1..=60 {
+            for delta in [7, 49, 60, 99, 100, -49] {
+                let mut steps = Steps::new(count, StepPosition::JumpEnd);
+
+                for step in 0..=count {
+                    let percent = step as f32 / count as f32;
+                    let expected = delta * step as i32 / count as i32;
+
+                    assert_eq!(steps.tween(delta, percent), expected, "{} of {} steps", step, count);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn frames() {
+        let frames = FixedTweener::steps(0, 5, 10, 5, StepPosition::JumpEnd, 1);
+        assert!(frames.eq([0, 1, 1, 2, 2, 3, 3, 4, 4, 5]));
+
+        let mut tweener = Tweener::steps_at(0, 10, 10, 2, StepPosition::JumpNone, 5);
+        assert_eq!(tweener.move_by(0), 10);
+    }
+}